@@ -0,0 +1,89 @@
+//! Compare rescanning the word list after every feedback with filtering the remaining
+//! candidates, as the agents do
+//!
+//! Run with `cargo bench -p wordle_ai --bench candidates`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use wordle_ai::{HeuristicGuesser, Knowledge, WordleAI, play};
+use wordle_core::{Game, Language, LetterResult};
+
+/// Number of games per language
+const NUM_GAMES: usize = 200;
+
+type Feedback = Vec<([char; 5], [LetterResult; 5])>;
+
+/// The feedback of games played by the heuristic agent, for a spread of solutions
+fn played_games(language: Language) -> Vec<Feedback> {
+    let wordlist = language.wordlist_array();
+    let mut ai = HeuristicGuesser::new(wordlist.to_vec());
+
+    wordlist
+        .iter()
+        .step_by(wordlist.len() / NUM_GAMES)
+        .take(NUM_GAMES)
+        .map(|solution| {
+            ai.reset();
+            let mut game = Game::new_with_word(6, language, *solution).unwrap();
+            play(&mut ai, &mut game).guesses
+        })
+        .collect()
+}
+
+/// Find the candidates after every feedback by checking every word of the list
+fn rescan(wordlist: &[[char; 5]], games: &[Feedback]) -> Duration {
+    let start = Instant::now();
+    for feedback in games {
+        let mut knowledge = Knowledge::for_wordlist(wordlist);
+        for &(guess, result) in feedback {
+            knowledge.update(guess, result);
+            let candidates: Vec<[char; 5]> = wordlist
+                .iter()
+                .filter(|word| **word != guess && knowledge.matches(word))
+                .copied()
+                .collect();
+            black_box(candidates);
+        }
+    }
+    start.elapsed()
+}
+
+/// Find the candidates after every feedback by filtering the previous candidates
+fn incremental(wordlist: &[[char; 5]], games: &[Feedback]) -> Duration {
+    let start = Instant::now();
+    for feedback in games {
+        let mut knowledge = Knowledge::for_wordlist(wordlist);
+        let mut candidates = wordlist.to_vec();
+        for &(guess, result) in feedback {
+            knowledge.update(guess, result);
+            candidates.retain(|word| *word != guess && knowledge.matches(word));
+            black_box(&candidates);
+        }
+    }
+    start.elapsed()
+}
+
+fn main() {
+    for &language in Language::all() {
+        let wordlist = language.wordlist_array();
+        let games = played_games(language);
+        let num_updates: usize = games.iter().map(Vec::len).sum();
+
+        let rescan_time = rescan(wordlist, &games);
+        let incremental_time = incremental(wordlist, &games);
+
+        println!(
+            "{:?}, {} games, {} updates:",
+            language, NUM_GAMES, num_updates
+        );
+        println!(
+            "  rescan word list:     {:>9.2?} per update",
+            rescan_time / num_updates as u32
+        );
+        println!(
+            "  filter candidates:    {:>9.2?} per update ({:.1}x)",
+            incremental_time / num_updates as u32,
+            rescan_time.as_secs_f64() / incremental_time.as_secs_f64()
+        );
+    }
+}
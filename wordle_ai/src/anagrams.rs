@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+/// Group the words that are anagrams of each other, i.e. use the same letters equally often
+///
+/// Anagrams score the same under any letter-frequency heuristic and only differ in where
+/// their letters go, so telling them apart takes a guess that tests positions. Only groups
+/// of at least two words are returned, in the order their first word appears in `words`,
+/// and each group keeps the order of `words`.
+pub fn group_anagrams(words: &[[char; 5]]) -> Vec<Vec<[char; 5]>> {
+    let mut groups: Vec<Vec<[char; 5]>> = Vec::new();
+    let mut group_of_letters: HashMap<[char; 5], usize> = HashMap::new();
+    for &word in words {
+        let mut letters = word;
+        letters.sort_unstable();
+        let index = *group_of_letters.entry(letters).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[index].push(word);
+    }
+
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(s: &str) -> [char; 5] {
+        s.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_group_anagrams() {
+        let words: Vec<_> = ["steal", "crane", "slate", "nacre", "plumb", "least"]
+            .into_iter()
+            .map(word)
+            .collect();
+
+        assert_eq!(
+            group_anagrams(&words),
+            vec![
+                vec![word("steal"), word("slate"), word("least")],
+                vec![word("crane"), word("nacre")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_anagrams_counts_repeated_letters() {
+        // Same letters, but not equally often
+        let words = [word("eerie"), word("eeire"), word("eirie")];
+        assert_eq!(
+            group_anagrams(&words),
+            vec![vec![word("eerie"), word("eeire")]]
+        );
+        assert!(group_anagrams(&[word("plumb")]).is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+use crate::knowledge::Knowledge;
+use std::collections::HashSet;
+use wordle_core::LetterResult;
+
+/// What a feedback-tracking agent knows about the current game: the knowledge about the
+/// hidden word, the words that still fit it, and the words it must not guess again
+///
+/// The agents only differ in how they pick a guess from this, so they all keep one of these
+/// and forward `update`, `mark_invalid` and `reset` to it.
+#[derive(Clone, Debug)]
+pub(crate) struct CandidateTracker {
+    /// All available words
+    wordlist: Vec<[char; 5]>,
+    /// Knowledge about the hidden word
+    knowledge: Knowledge,
+    /// Words that still match the knowledge and were neither guessed nor marked invalid
+    ///
+    /// Feedback only ever adds constraints, so this is filtered on every update instead of
+    /// rescanning the whole word list for every guess.
+    candidates: Vec<[char; 5]>,
+    /// Words that have been marked as invalid (not in the game's word list)
+    invalid_words: HashSet<[char; 5]>,
+    /// Words that have already been guessed this game
+    guessed_words: HashSet<[char; 5]>,
+    /// Word to always open with, instead of the strategy's own first guess
+    forced_opener: Option<[char; 5]>,
+    /// Whether to drop the oldest feedback instead of giving up when no candidate is left
+    lenient: bool,
+}
+
+impl CandidateTracker {
+    pub(crate) fn new(wordlist: Vec<[char; 5]>) -> Self {
+        let knowledge = Knowledge::for_wordlist(&wordlist);
+        Self {
+            candidates: wordlist.clone(),
+            wordlist,
+            knowledge,
+            invalid_words: HashSet::new(),
+            guessed_words: HashSet::new(),
+            forced_opener: None,
+            lenient: false,
+        }
+    }
+
+    /// Always open with the given word, which need not be in the word list
+    ///
+    /// `reset` keeps the forced opener, so every game starts with it.
+    pub(crate) fn set_forced_opener(&mut self, word: [char; 5]) {
+        self.forced_opener = Some(word);
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate, see
+    /// [`CandidateTracker::relax_if_lenient`]
+    pub(crate) fn set_lenient(&mut self) {
+        self.lenient = true;
+    }
+
+    /// The forced opener, as long as nothing has been guessed yet and it was not marked invalid
+    pub(crate) fn opener(&self) -> Option<[char; 5]> {
+        self.forced_opener
+            .filter(|opener| self.guessed_words.is_empty() && self.may_guess(opener))
+    }
+
+    pub(crate) fn knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
+
+    /// The words that could still be the solution
+    pub(crate) fn candidates(&self) -> &[[char; 5]] {
+        &self.candidates
+    }
+
+    /// The words guessed so far this game
+    pub(crate) fn guessed_words(&self) -> &HashSet<[char; 5]> {
+        &self.guessed_words
+    }
+
+    /// Whether the word was neither guessed nor marked invalid yet
+    pub(crate) fn may_guess(&self, word: &[char; 5]) -> bool {
+        !self.invalid_words.contains(word) && !self.guessed_words.contains(word)
+    }
+
+    /// Every word of the word list that may still be guessed, whether it could still be the
+    /// solution or not
+    pub(crate) fn allowed_guesses(&self) -> Vec<[char; 5]> {
+        self.wordlist
+            .iter()
+            .filter(|word| self.may_guess(word))
+            .copied()
+            .collect()
+    }
+
+    pub(crate) fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
+        self.guessed_words.insert(guess);
+        self.knowledge.update(guess, result);
+        self.candidates
+            .retain(|word| *word != guess && self.knowledge.matches(word));
+    }
+
+    pub(crate) fn mark_invalid(&mut self, word: [char; 5]) {
+        self.invalid_words.insert(word);
+        self.candidates.retain(|candidate| *candidate != word);
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.knowledge.clear();
+        self.invalid_words.clear();
+        self.guessed_words.clear();
+        self.candidates = self.wordlist.clone();
+    }
+
+    /// In lenient mode, drop the oldest feedback until some candidate matches again, see
+    /// [`Knowledge::relax`]
+    ///
+    /// Does nothing unless lenient mode is on, so agents call it before every guess.
+    pub(crate) fn relax_if_lenient(&mut self) {
+        while self.lenient && self.candidates.is_empty() && self.knowledge.relax() {
+            // Relaxing loosens the constraints, so words filtered out before may match again
+            self.rescan();
+        }
+    }
+
+    /// Rebuild the candidates from the whole word list
+    fn rescan(&mut self) {
+        self.candidates = self
+            .wordlist
+            .iter()
+            .filter(|&&word| {
+                !self.invalid_words.contains(&word)
+                    && !self.guessed_words.contains(&word)
+                    && self.knowledge.matches(&word)
+            })
+            .copied()
+            .collect();
+    }
+
+    /// See [`WordleAI::information_progress`](crate::WordleAI::information_progress)
+    pub(crate) fn information_progress(&self) -> f64 {
+        crate::information_gathered(self.wordlist.len(), self.candidates.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_filters_candidates() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+        ];
+        let mut tracker = CandidateTracker::new(wordlist);
+
+        // Position 0 must be 'a', and 'p', 'l', 'e' are not in the word
+        let result = [
+            LetterResult::Correct,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+        ];
+        tracker.update(['a', 'p', 'p', 'l', 'e'], result);
+
+        assert_eq!(tracker.candidates(), &[['a', 'b', 'o', 'u', 't']]);
+    }
+
+    #[test]
+    fn test_mark_invalid() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let mut tracker = CandidateTracker::new(wordlist);
+
+        tracker.mark_invalid(['a', 'p', 'p', 'l', 'e']);
+
+        assert_eq!(tracker.candidates(), &[['a', 'b', 'o', 'u', 't']]);
+        assert_eq!(tracker.allowed_guesses(), vec![['a', 'b', 'o', 'u', 't']]);
+    }
+
+    #[test]
+    fn test_guessed_word_is_never_a_candidate() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let mut tracker = CandidateTracker::new(wordlist);
+        let guess = ['a', 'p', 'p', 'l', 'e'];
+
+        // A mistaken all-green entry fixes every position, so the contradicting all-absent
+        // entry afterwards cannot remove any letter and the knowledge still matches 'apple'
+        tracker.update(guess, [LetterResult::Correct; 5]);
+        tracker.update(guess, [LetterResult::Absent; 5]);
+
+        assert!(tracker.knowledge().matches(&guess));
+        assert!(!tracker.candidates().contains(&guess));
+        assert!(!tracker.allowed_guesses().contains(&guess));
+    }
+
+    #[test]
+    fn test_candidates_filtered_incrementally() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+            ['s', 't', 'o', 'n', 'e'],
+        ];
+        let mut tracker = CandidateTracker::new(wordlist.clone());
+
+        let guess = ['s', 'l', 'a', 't', 'e'];
+        tracker.update(
+            guess,
+            wordle_core::take_guess(&['a', 'b', 'o', 'u', 't'], &guess),
+        );
+        tracker.mark_invalid(['h', 'e', 'l', 'l', 'o']);
+
+        // The same words a full rescan of the word list finds
+        let candidates = tracker.candidates().to_vec();
+        tracker.rescan();
+        assert_eq!(tracker.candidates(), candidates);
+        assert_eq!(candidates, vec![['a', 'b', 'o', 'u', 't']]);
+
+        // Resetting restores the whole word list
+        tracker.reset();
+        assert_eq!(tracker.candidates(), wordlist);
+        assert!(tracker.guessed_words().is_empty());
+    }
+
+    #[test]
+    fn test_opener_until_first_guess() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let opener = ['s', 'h', 'i', 'n', 'y'];
+        let mut tracker = CandidateTracker::new(wordlist);
+        assert_eq!(tracker.opener(), None);
+
+        tracker.set_forced_opener(opener);
+        assert_eq!(tracker.opener(), Some(opener));
+
+        // Any guess ends the opening, and resetting starts it again
+        tracker.update(['a', 'p', 'p', 'l', 'e'], [LetterResult::Absent; 5]);
+        assert_eq!(tracker.opener(), None);
+        tracker.reset();
+        assert_eq!(tracker.opener(), Some(opener));
+
+        // An opener the game rejects is not offered again
+        tracker.mark_invalid(opener);
+        assert_eq!(tracker.opener(), None);
+    }
+
+    #[test]
+    fn test_lenient_rescans_candidates() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let mut tracker = CandidateTracker::new(wordlist);
+
+        // A mistaken first entry rules out the 'a' of both words
+        tracker.update(['a', 'p', 'p', 'l', 'e'], [LetterResult::Absent; 5]);
+        tracker.update(['s', 'h', 'i', 'n', 'y'], [LetterResult::Absent; 5]);
+        tracker.relax_if_lenient();
+        assert!(tracker.candidates().is_empty());
+        assert!(!tracker.knowledge().is_relaxed());
+
+        // Only the oldest entry is dropped, and the guessed 'apple' stays excluded
+        tracker.set_lenient();
+        tracker.relax_if_lenient();
+        assert_eq!(tracker.candidates(), &[['a', 'b', 'o', 'u', 't']]);
+        assert!(tracker.knowledge().is_relaxed());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{WordleAI, partition::partition_candidates};
+use std::collections::BTreeMap;
+use wordle_core::{LetterResult, format_feedback};
+
+/// A precomputed strategy: what to guess, and where to go for each feedback
+///
+/// Following the tree from the root solves every candidate it was built for without
+/// running an AI, which makes it suitable for exporting to thin clients.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DecisionTree {
+    /// The word to guess at this point
+    pub guess: String,
+    /// The subtree for each possible feedback, keyed like `GYBBB` (Green/Yellow/Black)
+    ///
+    /// The all-green feedback ends the game and has no entry.
+    pub children: BTreeMap<String, DecisionTree>,
+}
+
+impl DecisionTree {
+    /// The number of guesses needed in the worst case
+    pub fn max_guesses(&self) -> usize {
+        1 + self
+            .children
+            .values()
+            .map(DecisionTree::max_guesses)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The subtree to continue with after receiving the given feedback for this guess
+    pub fn next(&self, feedback: &[LetterResult; 5]) -> Option<&DecisionTree> {
+        self.children.get(&format_feedback(feedback))
+    }
+}
+
+/// Build the full solve tree of an AI, starting with the given opener
+///
+/// At every node, the AI is reset and replays the feedback leading there before making its
+/// guess, so each branch sees exactly the knowledge a real game would. Whenever the AI has no
+/// suggestion, or suggests a word that neither is a candidate nor splits the candidates, the
+/// first remaining candidate is guessed instead. This guarantees every branch shrinks, so the
+/// tree always ends in solved candidates.
+///
+/// # Arguments
+/// * `ai` - The AI choosing the guesses after the opener
+/// * `opener` - The first guess
+/// * `candidates` - The words that could be the solution
+pub fn build_decision_tree(
+    ai: &mut dyn WordleAI,
+    opener: [char; 5],
+    candidates: &[[char; 5]],
+) -> DecisionTree {
+    build_subtree(ai, opener, candidates, &mut Vec::new())
+}
+
+fn build_subtree(
+    ai: &mut dyn WordleAI,
+    guess: [char; 5],
+    candidates: &[[char; 5]],
+    history: &mut Vec<([char; 5], [LetterResult; 5])>,
+) -> DecisionTree {
+    let mut children = BTreeMap::new();
+
+    for (feedback, group) in partition_candidates(&guess, candidates) {
+        if feedback == [LetterResult::Correct; 5] {
+            continue;
+        }
+
+        history.push((guess, feedback));
+        let next_guess = next_guess(ai, &group, history);
+        children.insert(
+            format_feedback(&feedback),
+            build_subtree(ai, next_guess, &group, history),
+        );
+        history.pop();
+    }
+
+    DecisionTree {
+        guess: guess.iter().collect(),
+        children,
+    }
+}
+
+/// Let the AI pick the guess after the given feedback, falling back to the first candidate
+fn next_guess(
+    ai: &mut dyn WordleAI,
+    candidates: &[[char; 5]],
+    history: &[([char; 5], [LetterResult; 5])],
+) -> [char; 5] {
+    if let [only] = candidates {
+        return *only;
+    }
+
+    ai.reset();
+    for &(guess, feedback) in history {
+        ai.update(guess, feedback);
+    }
+
+    ai.make_guess()
+        .filter(|guess| {
+            candidates.contains(guess) || partition_candidates(guess, candidates).len() > 1
+        })
+        .unwrap_or(candidates[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeuristicGuesser, RandomGuesser};
+
+    fn wordlist() -> Vec<[char; 5]> {
+        [
+            "crane", "crate", "trace", "house", "mouse", "louse", "slate", "plate",
+        ]
+        .iter()
+        .map(|word| word.chars().collect::<Vec<_>>().try_into().unwrap())
+        .collect()
+    }
+
+    /// Follow the tree for a solution, returning the number of guesses it takes
+    fn solve(tree: &DecisionTree, solution: &[char; 5]) -> Option<usize> {
+        let guess: [char; 5] = tree.guess.chars().collect::<Vec<_>>().try_into().unwrap();
+        let feedback = wordle_core::take_guess(solution, &guess);
+        if feedback == [LetterResult::Correct; 5] {
+            return Some(1);
+        }
+        solve(tree.next(&feedback)?, solution).map(|guesses| guesses + 1)
+    }
+
+    #[test]
+    fn test_decision_tree_solves_every_candidate() {
+        let wordlist = wordlist();
+        let mut ai = HeuristicGuesser::new(wordlist.clone());
+        let tree = build_decision_tree(&mut ai, ['c', 'r', 'a', 'n', 'e'], &wordlist);
+
+        assert_eq!(tree.guess, "crane");
+        for solution in &wordlist {
+            let guesses = solve(&tree, solution).unwrap();
+            assert!(guesses <= tree.max_guesses());
+        }
+    }
+
+    #[test]
+    fn test_decision_tree_feedback_keys() {
+        let wordlist = wordlist();
+        let mut ai = HeuristicGuesser::new(wordlist.clone());
+        let tree = build_decision_tree(&mut ai, ['c', 'r', 'a', 'n', 'e'], &wordlist);
+
+        // 'crate' is the only candidate with this feedback, so it is guessed next
+        let crate_branch = &tree.children["GGGBG"];
+        assert_eq!(crate_branch.guess, "crate");
+        assert!(crate_branch.children.is_empty());
+        assert!(!tree.children.contains_key("GGGGG"));
+    }
+
+    #[test]
+    fn test_decision_tree_without_progress_guesses_candidates() {
+        // The random guesser ignores feedback, so its suggestions are often useless;
+        // the tree must still terminate and solve every candidate
+        let wordlist = wordlist();
+        let mut ai = RandomGuesser::with_seed(vec![['x', 'x', 'x', 'x', 'x']], 42);
+        let tree = build_decision_tree(&mut ai, ['x', 'x', 'x', 'x', 'x'], &wordlist);
+
+        for solution in &wordlist {
+            assert!(solve(&tree, solution).is_some());
+        }
+        // The opener rules nothing out, so the first candidate is guessed after it
+        assert_eq!(tree.children["BBBBB"].guess, "crane");
+    }
+}
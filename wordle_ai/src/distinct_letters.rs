@@ -0,0 +1,47 @@
+use crate::DOUBLE_LETTER_CANDIDATES;
+use wordle_core::has_distinct_letters;
+
+/// Number of candidates at or below which [`with_distinct_letters_only`] stops restricting
+/// guesses
+///
+/// Above it, guesses with a repeated letter are skipped entirely. At or below it, the
+/// answer may well be such a word, so the restriction would rule out the right guess. Uses
+/// the same threshold as [`DOUBLE_LETTER_CANDIDATES`].
+///
+/// [`with_distinct_letters_only`]: crate::HeuristicGuesser::with_distinct_letters_only
+pub const DISTINCT_LETTERS_CANDIDATES: usize = DOUBLE_LETTER_CANDIDATES;
+
+/// Keep only the guesses with five distinct letters while more than
+/// [`DISTINCT_LETTERS_CANDIDATES`] candidates are left
+///
+/// The guesses are left as they are if none of them has five distinct letters.
+pub(crate) fn restrict_to_distinct_letters(guesses: &mut Vec<[char; 5]>, candidates: usize) {
+    if candidates > DISTINCT_LETTERS_CANDIDATES && guesses.iter().any(has_distinct_letters) {
+        guesses.retain(has_distinct_letters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restrict_to_distinct_letters() {
+        let apple = ['a', 'p', 'p', 'l', 'e'];
+        let slate = ['s', 'l', 'a', 't', 'e'];
+
+        let mut guesses = vec![apple, slate];
+        restrict_to_distinct_letters(&mut guesses, DISTINCT_LETTERS_CANDIDATES + 1);
+        assert_eq!(guesses, vec![slate]);
+
+        // Few candidates left: every guess is allowed again
+        let mut guesses = vec![apple, slate];
+        restrict_to_distinct_letters(&mut guesses, DISTINCT_LETTERS_CANDIDATES);
+        assert_eq!(guesses, vec![apple, slate]);
+
+        // No distinct-letter guess at all: nothing is filtered
+        let mut guesses = vec![apple];
+        restrict_to_distinct_letters(&mut guesses, DISTINCT_LETTERS_CANDIDATES + 1);
+        assert_eq!(guesses, vec![apple]);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::candidate_tracker::CandidateTracker;
+use crate::distinct_letters::restrict_to_distinct_letters;
+use crate::partition::pattern_counts;
+use crate::{WordleAI, knowledge::Knowledge};
+use wordle_core::LetterResult;
+
+/// AI #5: Elimination Guesser (Minimal Expected Remaining Candidates)
+///
+/// This AI picks guesses that minimize the expected number of candidates left after
+/// the feedback, i.e. that rule out the most candidates on average. It buckets the
+/// candidates by feedback pattern like the Entropy Guesser, but scores a guess by
+/// Σ (bucket_size² / total) instead of the entropy of the buckets.
+#[derive(Clone, Debug)]
+pub struct EliminationGuesser {
+    /// The feedback so far and the words that still fit it
+    tracker: CandidateTracker,
+    /// Whether to only guess words with five distinct letters while many candidates are left
+    distinct_letters_only: bool,
+}
+
+impl EliminationGuesser {
+    /// Create a new EliminationGuesser with the given word list
+    pub fn new(wordlist: Vec<[char; 5]>) -> Self {
+        Self {
+            tracker: CandidateTracker::new(wordlist),
+            distinct_letters_only: false,
+        }
+    }
+
+    /// Always open with the given word instead of the strategy's own first guess
+    ///
+    /// Later guesses follow the normal strategy; `reset` brings the forced opener back.
+    pub fn with_forced_opener(mut self, word: [char; 5]) -> Self {
+        self.tracker.set_forced_opener(word);
+        self
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate
+    ///
+    /// The oldest feedback is dropped until some word matches again, see [`Knowledge::relax`].
+    pub fn with_lenient_mode(mut self) -> Self {
+        self.tracker.set_lenient();
+        self
+    }
+
+    /// Only guess words with five distinct letters while more than
+    /// [`DISTINCT_LETTERS_CANDIDATES`](crate::DISTINCT_LETTERS_CANDIDATES) candidates are left
+    ///
+    /// This restricts the guesses, not the candidates: a solution with a repeated letter is
+    /// still found once few candidates are left.
+    pub fn with_distinct_letters_only(mut self) -> Self {
+        self.distinct_letters_only = true;
+        self
+    }
+
+    /// Compute the expected number of candidates remaining after a guess
+    fn expected_remaining(&self, guess: &[char; 5], candidates: &[[char; 5]]) -> f64 {
+        let total = candidates.len() as f64;
+        pattern_counts(guess, candidates)
+            .values()
+            .map(|&count| (count * count) as f64 / total)
+            .sum()
+    }
+}
+
+impl WordleAI for EliminationGuesser {
+    fn make_guess(&mut self) -> Option<[char; 5]> {
+        if let Some(opener) = self.tracker.opener() {
+            return Some(opener);
+        }
+
+        self.tracker.relax_if_lenient();
+        let candidates = self.tracker.candidates();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        // With only 1-2 candidates left, no guess can do better than guessing one of them
+        if candidates.len() <= 2 {
+            return Some(candidates[0]);
+        }
+
+        // Compute the expected remaining candidates for every possible guess and take min
+        let mut guesses = self.tracker.allowed_guesses();
+        if self.distinct_letters_only {
+            restrict_to_distinct_letters(&mut guesses, candidates.len());
+        }
+
+        guesses
+            .into_iter()
+            .map(|word| (word, self.expected_remaining(&word, candidates)))
+            .min_by(|(_, remaining_a), (_, remaining_b)| {
+                remaining_a.partial_cmp(remaining_b).unwrap()
+            })
+            .map(|(word, _)| word)
+    }
+
+    fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
+        self.tracker.update(guess, result);
+    }
+
+    fn mark_invalid(&mut self, word: [char; 5]) {
+        self.tracker.mark_invalid(word);
+    }
+
+    fn reset(&mut self) {
+        self.tracker.reset();
+    }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Elimination Guesser"
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        Some(self.tracker.knowledge())
+    }
+
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        Some(self.tracker.candidates())
+    }
+
+    fn information_progress(&self) -> f64 {
+        self.tracker.information_progress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_remaining() {
+        let candidates = vec![
+            ['a', 'b', 'c', 'd', 'e'],
+            ['f', 'g', 'h', 'i', 'j'],
+            ['k', 'l', 'm', 'n', 'o'],
+            ['k', 'l', 'm', 'n', 'p'],
+        ];
+        let ai = EliminationGuesser::new(candidates.clone());
+
+        // 'abcde' splits the candidates into buckets of sizes 1 (itself) and 3 (all absent)
+        // Expected remaining: (1² + 3²) / 4 = 2.5
+        let remaining = ai.expected_remaining(&['a', 'b', 'c', 'd', 'e'], &candidates);
+        assert!((remaining - 2.5).abs() < 0.01);
+
+        // 'klmno' splits them into buckets of sizes 1, 1 (klmnp) and 2 (all absent)
+        // Expected remaining: (1² + 1² + 2²) / 4 = 1.5
+        let remaining = ai.expected_remaining(&['k', 'l', 'm', 'n', 'o'], &candidates);
+        assert!((remaining - 1.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_elimination_guesser_picks_best_split() {
+        let wordlist = vec![
+            ['a', 'b', 'c', 'd', 'e'],
+            ['f', 'g', 'h', 'i', 'j'],
+            ['k', 'l', 'm', 'n', 'o'],
+            ['k', 'l', 'm', 'n', 'p'],
+        ];
+        let mut ai = EliminationGuesser::new(wordlist);
+
+        // 'klmno' and 'klmnp' both leave 1.5 candidates on average, the others 2.5
+        let guess = ai.make_guess().unwrap();
+        assert_eq!(guess, ['k', 'l', 'm', 'n', 'o']);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{WordleAI, knowledge::Knowledge};
+use crate::candidate_tracker::CandidateTracker;
+use crate::distinct_letters::restrict_to_distinct_letters;
+use crate::{WordleAI, knowledge::Knowledge, partition::pattern_counts};
+use rand::prelude::*;
 use std::collections::{HashMap, HashSet};
 use wordle_core::LetterResult;
 
@@ -6,44 +9,134 @@ use wordle_core::LetterResult;
 ///
 /// This AI picks guesses that maximize the expected information gain (entropy),
 /// i.e., guesses that most effectively split the remaining candidate set.
+#[derive(Clone, Debug)]
 pub struct EntropyGuesser {
-    /// All allowed guesses
-    wordlist: Vec<[char; 5]>,
-    /// Knowledge about the hidden word
-    knowledge: Knowledge,
-    /// Words that have been marked invalid (not in game's list)
-    invalid_words: HashSet<[char; 5]>,
+    /// The feedback so far and the words that still fit it
+    tracker: CandidateTracker,
+    /// Whether to only guess words with five distinct letters while many candidates are left
+    distinct_letters_only: bool,
+    /// Optional word frequencies, used to prefer more common words when breaking ties
+    frequencies: Option<HashMap<[char; 5], f64>>,
+    /// Maximum number of candidates to evaluate entropy against, see [`Self::with_sample_limit`]
+    sample_limit: Option<usize>,
 }
 
+/// Entropies closer than this to the best one are considered tied
+const ENTROPY_TIE_EPSILON: f64 = 1e-9;
+
+/// Seed for sampling candidates, fixed so that sampled guesses are reproducible
+const SAMPLE_SEED: u64 = 0x5eed;
+
 impl EntropyGuesser {
     pub fn new(wordlist: Vec<[char; 5]>) -> Self {
         Self {
-            wordlist,
-            knowledge: Knowledge::new(),
-            invalid_words: HashSet::new(),
+            tracker: CandidateTracker::new(wordlist),
+            distinct_letters_only: false,
+            frequencies: None,
+            sample_limit: None,
         }
     }
 
-    /// Get all candidate words that match current knowledge
-    fn get_candidates(&self) -> Vec<[char; 5]> {
-        self.wordlist
-            .iter()
-            .filter(|&&w| !self.invalid_words.contains(&w) && self.knowledge.matches(&w))
-            .copied()
-            .collect()
+    /// Create a new EntropyGuesser that breaks ties in favor of more frequent words
+    ///
+    /// Words missing from `frequencies` are treated as having frequency 0.
+    pub fn with_frequencies(
+        wordlist: Vec<[char; 5]>,
+        frequencies: HashMap<[char; 5], f64>,
+    ) -> Self {
+        Self {
+            frequencies: Some(frequencies),
+            ..Self::new(wordlist)
+        }
     }
 
-    /// Compute expected information gain (entropy) for a guess
-    fn guess_entropy(&self, guess: &[char; 5], candidates: &[[char; 5]]) -> f64 {
-        let mut pattern_counts: HashMap<[LetterResult; 5], usize> = HashMap::new();
+    /// Get the frequency of a word, or 0 if no frequencies are known for it
+    fn frequency(&self, word: &[char; 5]) -> f64 {
+        self.frequencies
+            .as_ref()
+            .and_then(|frequencies| frequencies.get(word).copied())
+            .unwrap_or(0.0)
+    }
+
+    /// Always open with the given word instead of the strategy's own first guess
+    ///
+    /// Later guesses follow the normal strategy; `reset` brings the forced opener back.
+    pub fn with_forced_opener(mut self, word: [char; 5]) -> Self {
+        self.tracker.set_forced_opener(word);
+        self
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate
+    ///
+    /// The oldest feedback is dropped until some word matches again, see [`Knowledge::relax`].
+    pub fn with_lenient_mode(mut self) -> Self {
+        self.tracker.set_lenient();
+        self
+    }
+
+    /// Only guess words with five distinct letters while more than
+    /// [`DISTINCT_LETTERS_CANDIDATES`](crate::DISTINCT_LETTERS_CANDIDATES) candidates are left
+    ///
+    /// This restricts the guesses, not the candidates: a solution with a repeated letter is
+    /// still found once few candidates are left.
+    pub fn with_distinct_letters_only(mut self) -> Self {
+        self.distinct_letters_only = true;
+        self
+    }
+
+    /// Estimate entropies on a random sample of at most `limit` candidates
+    ///
+    /// Scoring every guess against every candidate is exact but slow while many candidates
+    /// are left, mostly on the first moves. With a limit, a guess's entropy is computed
+    /// against a fixed-seed random sample of the candidates instead, which approximates the
+    /// pattern distribution and may pick a slightly worse guess. Once the candidates fit in
+    /// the limit, the search is exact again.
+    pub fn with_sample_limit(mut self, limit: usize) -> Self {
+        self.sample_limit = Some(limit.max(1));
+        self
+    }
+
+    /// Draw the sample of candidates to evaluate entropy against, if the limit is exceeded
+    fn sample_candidates(&self, candidates: &[[char; 5]]) -> Option<Vec<[char; 5]>> {
+        let limit = self
+            .sample_limit
+            .filter(|&limit| candidates.len() > limit)?;
+        let mut rng = StdRng::seed_from_u64(SAMPLE_SEED);
+        Some(
+            candidates
+                .choose_multiple(&mut rng, limit)
+                .copied()
+                .collect(),
+        )
+    }
 
-        for &candidate in candidates {
-            let pattern = wordle_core::take_guess(&candidate, guess);
-            *pattern_counts.entry(pattern).or_insert(0) += 1;
+    /// The expected information of any word as a guess, in bits, e.g. to rate a guess the
+    /// user is considering against the AI's own
+    ///
+    /// Always evaluated against all current candidates, even with a sample limit.
+    /// Returns `None` if no candidate is left.
+    pub fn evaluate_guess(&self, word: &[char; 5]) -> Option<f64> {
+        let candidates = self.tracker.candidates();
+        if candidates.is_empty() {
+            return None;
         }
+        Some(self.guess_entropy(word, candidates))
+    }
 
+    /// Words that may be guessed next: neither guessed nor marked invalid, and with five
+    /// distinct letters if that is required while this many candidates are left
+    fn allowed_guesses(&self, candidates: usize) -> Vec<[char; 5]> {
+        let mut guesses = self.tracker.allowed_guesses();
+        if self.distinct_letters_only {
+            restrict_to_distinct_letters(&mut guesses, candidates);
+        }
+        guesses
+    }
+
+    /// Compute expected information gain (entropy) for a guess
+    fn guess_entropy(&self, guess: &[char; 5], candidates: &[[char; 5]]) -> f64 {
         let total = candidates.len() as f64;
-        pattern_counts
+        pattern_counts(guess, candidates)
             .values()
             .map(|&count| {
                 let p = count as f64 / total;
@@ -55,7 +148,12 @@ impl EntropyGuesser {
 
 impl WordleAI for EntropyGuesser {
     fn make_guess(&mut self) -> Option<[char; 5]> {
-        let candidates = self.get_candidates();
+        if let Some(opener) = self.tracker.opener() {
+            return Some(opener);
+        }
+
+        self.tracker.relax_if_lenient();
+        let candidates = self.tracker.candidates();
 
         if candidates.is_empty() {
             return None;
@@ -63,30 +161,218 @@ impl WordleAI for EntropyGuesser {
 
         // When we've narrowed down to very few candidates, just guess one of them
         // When there's only 1-2 candidates left, all guesses have entropy ≈ 0,
-        // so we might as well guess the actual answer
+        // so we might as well guess the actual answer (the most frequent one, if known)
         if candidates.len() <= 2 {
-            return Some(candidates[0]);
+            return candidates.iter().copied().reduce(|best, word| {
+                if self.frequency(&word) > self.frequency(&best) {
+                    word
+                } else {
+                    best
+                }
+            });
+        }
+
+        // Compute entropy for every possible guess, estimated on a sample if there is a limit
+        let sample = self.sample_candidates(candidates);
+        let evaluated = sample.as_deref().unwrap_or(candidates);
+        let scored: Vec<([char; 5], f64)> = self
+            .allowed_guesses(candidates.len())
+            .into_iter()
+            .map(|word| (word, self.guess_entropy(&word, evaluated)))
+            .collect();
+
+        // Without frequencies, simply take the max
+        if self.frequencies.is_none() {
+            return scored
+                .into_iter()
+                .max_by(|(_, entropy_a), (_, entropy_b)| entropy_a.partial_cmp(entropy_b).unwrap())
+                .map(|(word, _)| word);
         }
 
-        // Compute entropy for every possible guess and take max
-        self.wordlist
+        // Among the guesses tied for max entropy, prefer one that could still be the answer,
+        // and among those the most frequent one
+        let best_entropy = scored
             .iter()
-            .filter(|&word| !self.invalid_words.contains(word))
-            .map(|word| (word, self.guess_entropy(word, &candidates)))
-            .max_by(|(_, entropy_a), (_, entropy_b)| entropy_a.partial_cmp(entropy_b).unwrap())
-            .map(|(word, _)| *word)
+            .map(|&(_, entropy)| entropy)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let candidate_set: HashSet<[char; 5]> = candidates.iter().copied().collect();
+        scored
+            .into_iter()
+            .filter(|&(_, entropy)| best_entropy - entropy < ENTROPY_TIE_EPSILON)
+            .map(|(word, _)| (word, candidate_set.contains(&word), self.frequency(&word)))
+            .max_by(|(_, candidate_a, freq_a), (_, candidate_b, freq_b)| {
+                (candidate_a, freq_a)
+                    .partial_cmp(&(candidate_b, freq_b))
+                    .unwrap()
+            })
+            .map(|(word, _, _)| word)
     }
 
     fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
-        self.knowledge.update(guess, result);
+        self.tracker.update(guess, result);
     }
 
     fn mark_invalid(&mut self, word: [char; 5]) {
-        self.invalid_words.insert(word);
+        self.tracker.mark_invalid(word);
     }
 
     fn reset(&mut self) {
-        self.knowledge = Knowledge::new();
-        self.invalid_words.clear();
+        self.tracker.reset();
+    }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Entropy Guesser"
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        Some(self.tracker.knowledge())
+    }
+
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        Some(self.tracker.candidates())
+    }
+
+    fn explain_guess(&self, word: &[char; 5]) -> Vec<String> {
+        let Some(bits) = self.evaluate_guess(word) else {
+            return Vec::new();
+        };
+        let candidates = self.tracker.candidates();
+        let patterns = pattern_counts(word, candidates).len();
+        vec![
+            format!("{:.2} bits of expected information", bits),
+            format!(
+                "{} distinct response patterns over {} candidates",
+                patterns,
+                candidates.len()
+            ),
+        ]
+    }
+
+    fn information_progress(&self) -> f64 {
+        self.tracker.information_progress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy_guesser_endgame_without_frequencies() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let mut ai = EntropyGuesser::new(wordlist);
+
+        // Without frequencies, the first remaining candidate is guessed
+        assert_eq!(ai.make_guess(), Some(['a', 'p', 'p', 'l', 'e']));
+    }
+
+    #[test]
+    fn test_entropy_guesser_endgame_prefers_frequent_word() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let frequencies = HashMap::from([
+            (['a', 'p', 'p', 'l', 'e'], 0.1),
+            (['a', 'b', 'o', 'u', 't'], 0.9),
+        ]);
+        let mut ai = EntropyGuesser::with_frequencies(wordlist, frequencies);
+
+        assert_eq!(ai.make_guess(), Some(['a', 'b', 'o', 'u', 't']));
+    }
+
+    #[test]
+    fn test_entropy_guesser_tie_prefers_frequent_candidate() {
+        // Each of these words splits the other two perfectly, so all three tie on entropy
+        let wordlist = vec![
+            ['a', 'b', 'c', 'd', 'e'],
+            ['f', 'g', 'h', 'i', 'j'],
+            ['k', 'l', 'm', 'n', 'o'],
+        ];
+        let frequencies = HashMap::from([(['f', 'g', 'h', 'i', 'j'], 0.5)]);
+        let mut ai = EntropyGuesser::with_frequencies(wordlist, frequencies);
+
+        assert_eq!(ai.make_guess(), Some(['f', 'g', 'h', 'i', 'j']));
+    }
+
+    #[test]
+    fn test_entropy_guesser_sample_candidates() {
+        let wordlist: Vec<[char; 5]> = ('a'..='z').map(|c| [c, 'x', 'y', 'z', 'w']).collect();
+        let ai = EntropyGuesser::new(wordlist.clone()).with_sample_limit(10);
+
+        // A fixed-seed subset of the requested size, without duplicates
+        let sample = ai.sample_candidates(&wordlist).unwrap();
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|word| wordlist.contains(word)));
+        assert_eq!(sample.iter().collect::<HashSet<_>>().len(), 10);
+        assert_eq!(ai.sample_candidates(&wordlist), Some(sample));
+
+        // No sampling within the limit, or without a limit
+        assert_eq!(ai.sample_candidates(&wordlist[..10]), None);
+        assert_eq!(
+            EntropyGuesser::new(wordlist.clone()).sample_candidates(&wordlist),
+            None
+        );
+    }
+
+    #[test]
+    fn test_entropy_guesser_sample_limit_within_candidates_is_exact() {
+        let wordlist = vec![
+            ['c', 'r', 'a', 'n', 'e'],
+            ['c', 'r', 'a', 't', 'e'],
+            ['t', 'r', 'a', 'c', 'e'],
+            ['h', 'o', 'u', 's', 'e'],
+            ['m', 'o', 'u', 's', 'e'],
+        ];
+        let mut exact = EntropyGuesser::new(wordlist.clone());
+        let mut sampled = EntropyGuesser::new(wordlist).with_sample_limit(5);
+
+        assert_eq!(sampled.make_guess(), exact.make_guess());
+    }
+
+    #[test]
+    fn test_entropy_guesser_evaluate_guess() {
+        let wordlist = vec![
+            ['a', 'b', 'c', 'd', 'e'],
+            ['a', 'b', 'c', 'd', 'f'],
+            ['a', 'b', 'c', 'g', 'e'],
+            ['a', 'b', 'c', 'g', 'f'],
+        ];
+        let mut ai = EntropyGuesser::new(wordlist.clone());
+
+        // Tells both open positions apart: four equally likely patterns, 2 bits
+        let both = ai.evaluate_guess(&['x', 'x', 'x', 'd', 'e']).unwrap();
+        assert!((both - 2.0).abs() < 1e-9);
+        // Only tells the last position apart: 1 bit
+        let last = ai.evaluate_guess(&['x', 'x', 'x', 'x', 'e']).unwrap();
+        assert!((last - 1.0).abs() < 1e-9);
+        // Reveals nothing new
+        assert_eq!(ai.evaluate_guess(&['a', 'b', 'c', 'x', 'x']), Some(0.0));
+
+        for word in &wordlist {
+            ai.mark_invalid(*word);
+        }
+        assert_eq!(ai.evaluate_guess(&['x', 'x', 'x', 'd', 'e']), None);
+    }
+
+    #[test]
+    fn test_entropy_guesser_explain_guess() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+            ['s', 't', 'o', 'n', 'e'],
+        ];
+        let ai = EntropyGuesser::new(wordlist);
+
+        // Every candidate responds differently to 'apple', so it tells log2(4) bits
+        assert_eq!(
+            ai.explain_guess(&['a', 'p', 'p', 'l', 'e']),
+            vec![
+                "2.00 bits of expected information".to_string(),
+                "4 distinct response patterns over 4 candidates".to_string(),
+            ]
+        );
     }
 }
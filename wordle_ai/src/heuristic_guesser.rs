@@ -1,6 +1,8 @@
+use crate::candidate_tracker::CandidateTracker;
+use crate::distinct_letters::restrict_to_distinct_letters;
 use crate::{WordleAI, knowledge::Knowledge};
 use std::collections::{HashMap, HashSet};
-use wordle_core::LetterResult;
+use wordle_core::{LetterResult, display_upper};
 
 /// AI #3: Heuristic Guesser
 ///
@@ -9,16 +11,25 @@ use wordle_core::LetterResult;
 /// each word by summing S(p) = -(p² + (1-p)²) for each unique letter, where p is the
 /// letter's frequency. This formula is maximized when p = 0.5, encouraging guesses
 /// with letters that appear in about half the candidates.
-#[derive(Clone)]
+///
+/// With [`HeuristicGuesser::with_late_double_letter_bias`], repeated letters are scored too
+/// once few candidates are left, see [`DOUBLE_LETTER_CANDIDATES`].
+#[derive(Clone, Debug)]
 pub struct HeuristicGuesser {
-    /// All available words
-    wordlist: Vec<[char; 5]>,
-    /// Knowledge about the hidden word
-    knowledge: Knowledge,
-    /// Words that have been marked as invalid (not in the game's word list)
-    invalid_words: HashSet<[char; 5]>,
+    /// The feedback so far and the words that still fit it
+    tracker: CandidateTracker,
+    /// Whether to only guess words with five distinct letters while many candidates are left
+    distinct_letters_only: bool,
+    /// Whether to also score repeated letters once few candidates are left
+    late_double_letter_bias: bool,
 }
 
+/// Number of candidates at or below which the late double letter bias applies
+///
+/// Early on, a repeated letter wastes a position that could test a new letter. In the
+/// endgame, whether the answer repeats a letter is often exactly what is left to find out.
+pub const DOUBLE_LETTER_CANDIDATES: usize = 20;
+
 fn entropy(p: f64) -> f64 {
     if p <= 0.0 || p >= 1.0 {
         return 0.0;
@@ -31,19 +42,73 @@ impl HeuristicGuesser {
     /// Create a new HeuristicGuesser with the given word list
     pub fn new(wordlist: Vec<[char; 5]>) -> Self {
         Self {
-            wordlist,
-            knowledge: Knowledge::new(),
-            invalid_words: HashSet::new(),
+            tracker: CandidateTracker::new(wordlist),
+            distinct_letters_only: false,
+            late_double_letter_bias: false,
         }
     }
 
-    /// Get all candidate words that match current knowledge
-    fn get_candidates(&self) -> Vec<[char; 5]> {
-        self.wordlist
-            .iter()
-            .filter(|&&word| !self.invalid_words.contains(&word) && self.knowledge.matches(&word))
-            .copied()
-            .collect()
+    /// Always open with the given word instead of the strategy's own first guess
+    ///
+    /// Later guesses follow the normal strategy; `reset` brings the forced opener back.
+    pub fn with_forced_opener(mut self, word: [char; 5]) -> Self {
+        self.tracker.set_forced_opener(word);
+        self
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate
+    ///
+    /// The oldest feedback is dropped until some word matches again, see [`Knowledge::relax`].
+    pub fn with_lenient_mode(mut self) -> Self {
+        self.tracker.set_lenient();
+        self
+    }
+
+    /// Only guess words with five distinct letters while more than
+    /// [`DISTINCT_LETTERS_CANDIDATES`](crate::DISTINCT_LETTERS_CANDIDATES) candidates are left
+    ///
+    /// This restricts the guesses, not the candidates: a solution with a repeated letter is
+    /// still found once few candidates are left.
+    pub fn with_distinct_letters_only(mut self) -> Self {
+        self.distinct_letters_only = true;
+        self
+    }
+
+    /// Stop deduplicating letters when scoring once few candidates are left
+    ///
+    /// The second occurrence of a letter is then scored by how many candidates contain the
+    /// letter at least twice, which rewards probing double letters in the endgame.
+    pub fn with_late_double_letter_bias(mut self) -> Self {
+        self.late_double_letter_bias = true;
+        self
+    }
+
+    /// Score words against the given candidates, as `make_guess` does
+    fn scorer(&self, candidates: &[[char; 5]]) -> impl Fn(&[char; 5]) -> f64 + '_ {
+        let frequencies = self.calculate_letter_frequencies(candidates);
+        let double_frequencies = (self.late_double_letter_bias
+            && candidates.len() <= DOUBLE_LETTER_CANDIDATES)
+            .then(|| self.calculate_double_letter_frequencies(candidates));
+
+        move |word| match &double_frequencies {
+            Some(double_frequencies) => {
+                self.score_word_with_doubles(word, &frequencies, double_frequencies)
+            }
+            None => self.score_word(word, &frequencies),
+        }
+    }
+
+    /// Score any word against the current candidates, e.g. to rate a guess the user is
+    /// considering against the AI's own
+    ///
+    /// The score is the one `make_guess` maximizes, in bits of expected information from
+    /// its letters. Returns `None` if no candidate is left.
+    pub fn evaluate_guess(&self, word: &[char; 5]) -> Option<f64> {
+        let candidates = self.tracker.candidates();
+        if candidates.is_empty() {
+            return None;
+        }
+        Some(self.scorer(candidates)(word))
     }
 
     /// Calculate letter frequencies across all candidate words
@@ -75,6 +140,22 @@ impl HeuristicGuesser {
             .collect()
     }
 
+    /// Calculate the fraction of candidate words containing each letter at least twice
+    fn calculate_double_letter_frequencies(&self, candidates: &[[char; 5]]) -> HashMap<char, f64> {
+        let mut letter_counts: HashMap<char, usize> = HashMap::new();
+
+        for word in candidates {
+            for letter in repeated_letters(word) {
+                *letter_counts.entry(letter).or_insert(0) += 1;
+            }
+        }
+
+        letter_counts
+            .into_iter()
+            .map(|(letter, count)| (letter, count as f64 / candidates.len() as f64))
+            .collect()
+    }
+
     /// Score a word based on letter frequencies
     /// S(p) = -(p² + (1-p)²) for each unique letter
     fn score_word(&self, word: &[char; 5], frequencies: &HashMap<char, f64>) -> f64 {
@@ -89,44 +170,146 @@ impl HeuristicGuesser {
             })
             .sum()
     }
+
+    /// Score a word like [`Self::score_word`], plus S(p) for each repeated letter, where p is
+    /// the fraction of candidates containing that letter at least twice
+    fn score_word_with_doubles(
+        &self,
+        word: &[char; 5],
+        frequencies: &HashMap<char, f64>,
+        double_frequencies: &HashMap<char, f64>,
+    ) -> f64 {
+        let doubles: f64 = repeated_letters(word)
+            .iter()
+            .map(|letter| entropy(double_frequencies.get(letter).copied().unwrap_or(0.0)))
+            .sum();
+        self.score_word(word, frequencies) + doubles
+    }
+}
+
+/// Letters that appear more than once in the word
+fn repeated_letters(word: &[char; 5]) -> HashSet<char> {
+    word.iter()
+        .copied()
+        .filter(|letter| word.iter().filter(|&c| c == letter).count() > 1)
+        .collect()
 }
 
 impl WordleAI for HeuristicGuesser {
     fn make_guess(&mut self) -> Option<[char; 5]> {
-        let candidates = self.get_candidates();
+        self.suggest_top_n(1).into_iter().next()
+    }
 
-        if candidates.is_empty() {
-            return None;
+    fn suggest_top_n(&mut self, n: usize) -> Vec<[char; 5]> {
+        let opener = self.tracker.opener();
+        if opener.is_some() && n <= 1 {
+            return opener.into_iter().take(n).collect();
         }
 
-        // Calculate letter frequencies
-        let frequencies = self.calculate_letter_frequencies(&candidates);
+        self.tracker.relax_if_lenient();
+        let candidates = self.tracker.candidates();
 
-        // Find the word with the highest score
-        candidates
-            .iter()
-            .map(|word| (word, self.score_word(word, &frequencies)))
-            .max_by(|(_, score_a), (_, score_b)| score_a.partial_cmp(score_b).unwrap())
-            .map(|(word, _)| *word)
+        // Letters are scored against all candidates, but only some of them may be guessed
+        let mut guesses = candidates.to_vec();
+        if self.distinct_letters_only {
+            restrict_to_distinct_letters(&mut guesses, candidates.len());
+        }
+
+        let score = self.scorer(candidates);
+        let mut scored: Vec<([char; 5], f64)> =
+            guesses.iter().map(|&word| (word, score(&word))).collect();
+
+        // Highest score first; among equal scores the later word comes first
+        scored.reverse();
+        scored.sort_by(|(_, score_a), (_, score_b)| score_b.partial_cmp(score_a).unwrap());
+
+        opener
+            .into_iter()
+            .chain(
+                scored
+                    .into_iter()
+                    .map(|(word, _)| word)
+                    .filter(|&word| Some(word) != opener),
+            )
+            .take(n)
+            .collect()
     }
 
     fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
-        self.knowledge.update(guess, result);
+        self.tracker.update(guess, result);
     }
 
     fn mark_invalid(&mut self, word: [char; 5]) {
-        self.invalid_words.insert(word);
+        self.tracker.mark_invalid(word);
     }
 
     fn reset(&mut self) {
-        self.knowledge = Knowledge::new();
-        self.invalid_words.clear();
+        self.tracker.reset();
+    }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Heuristic Guesser"
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        Some(self.tracker.knowledge())
+    }
+
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        Some(self.tracker.candidates())
+    }
+
+    fn explain_guess(&self, word: &[char; 5]) -> Vec<String> {
+        let candidates = self.tracker.candidates();
+        let Some(total) = self.evaluate_guess(word) else {
+            return Vec::new();
+        };
+        let frequencies = self.calculate_letter_frequencies(candidates);
+
+        // Every distinct letter with the share of candidates containing it, best first
+        let mut letters: Vec<(char, f64)> = word
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|letter| (letter, frequencies.get(&letter).copied().unwrap_or(0.0)))
+            .collect();
+        letters.sort_by(|(letter_a, p_a), (letter_b, p_b)| {
+            entropy(*p_b)
+                .partial_cmp(&entropy(*p_a))
+                .unwrap()
+                .then(letter_a.cmp(letter_b))
+        });
+
+        std::iter::once(format!(
+            "Scores {:.2} bits over {} candidates",
+            total,
+            candidates.len()
+        ))
+        .chain(letters.into_iter().map(|(letter, p)| {
+            format!(
+                "{}: in {:.0}% of the candidates ({:.2} bits)",
+                display_upper(letter),
+                p * 100.0,
+                entropy(p)
+            )
+        }))
+        .collect()
+    }
+
+    fn information_progress(&self) -> f64 {
+        self.tracker.information_progress()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wordle_core::has_distinct_letters;
 
     #[test]
     fn test_calculate_letter_frequencies() {
@@ -190,6 +373,43 @@ mod tests {
         assert!((score - 4.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_score_word_with_doubles() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e']];
+        let ai = HeuristicGuesser::new(wordlist);
+
+        let frequencies = HashMap::from([('a', 0.5), ('p', 0.5), ('l', 0.5), ('e', 0.5)]);
+        let double_frequencies = HashMap::from([('p', 0.5)]);
+
+        let word = ['a', 'p', 'p', 'l', 'e'];
+        let score = ai.score_word_with_doubles(&word, &frequencies, &double_frequencies);
+
+        // 4 unique letters plus the repeated 'p', each with p=0.5
+        assert!((score - 5.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_late_double_letter_bias_probes_double_letter() {
+        // All candidates share 'a', 'r' and 'e'; only 'ferry' and 'merry' repeat a letter
+        let wordlist = vec![
+            ['b', 'a', 'k', 'e', 'r'],
+            ['f', 'e', 'r', 'r', 'y'],
+            ['m', 'e', 'r', 'r', 'y'],
+            ['f', 'a', 'r', 'e', 'd'],
+        ];
+        let double_frequencies =
+            HeuristicGuesser::new(wordlist.clone()).calculate_double_letter_frequencies(&wordlist);
+        assert_eq!(double_frequencies, HashMap::from([('r', 0.5)]));
+
+        // Without the bias, the repeated 'r' counts once and 'fared' scores highest
+        let mut ai = HeuristicGuesser::new(wordlist.clone());
+        assert_eq!(ai.make_guess(), Some(['f', 'a', 'r', 'e', 'd']));
+
+        // With it, the second 'r' splits the candidates in half and makes 'ferry' the best probe
+        let mut ai = HeuristicGuesser::new(wordlist).with_late_double_letter_bias();
+        assert_eq!(ai.make_guess(), Some(['f', 'e', 'r', 'r', 'y']));
+    }
+
     #[test]
     fn test_heuristic_guesser_picks_best_word() {
         // Create a simple wordlist where we can predict the best word
@@ -214,61 +434,112 @@ mod tests {
     }
 
     #[test]
-    fn test_heuristic_guesser_filters_candidates() {
+    fn test_heuristic_guesser_suggest_top_n() {
         let wordlist = vec![
             ['a', 'p', 'p', 'l', 'e'],
             ['a', 'b', 'o', 'u', 't'],
             ['h', 'e', 'l', 'l', 'o'],
+            ['s', 't', 'o', 'n', 'e'],
         ];
-        let mut ai = HeuristicGuesser::new(wordlist);
-
-        // Simulate guess with first letter 'a' being correct
-        let guess = ['a', 'p', 'p', 'l', 'e'];
-        let result = [
-            LetterResult::Correct,
-            LetterResult::Absent,
-            LetterResult::Absent,
-            LetterResult::Absent,
-            LetterResult::Absent,
-        ];
-        ai.update(guess, result);
+        let mut ai = HeuristicGuesser::new(wordlist.clone());
 
-        // Get candidates - should only include words starting with 'a'
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 1);
-        assert!(candidates.contains(&['a', 'b', 'o', 'u', 't']));
+        // The best suggestion is the guess that would be made
+        let suggestions = ai.suggest_top_n(3);
+        assert_eq!(suggestions.len(), 3);
+        assert_eq!(suggestions.first().copied(), ai.make_guess());
+        assert!(suggestions.iter().all(|word| wordlist.contains(word)));
+
+        // Suggestions are distinct and capped by the number of candidates
+        let all = ai.suggest_top_n(10);
+        let unique: HashSet<_> = all.iter().collect();
+        assert_eq!(all.len(), 4);
+        assert_eq!(unique.len(), 4);
+        assert_eq!(&all[..3], &suggestions[..]);
     }
 
     #[test]
-    fn test_heuristic_guesser_mark_invalid() {
+    fn test_heuristic_guesser_suggest_top_n_with_forced_opener() {
         let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
-        let mut ai = HeuristicGuesser::new(wordlist);
-
-        // Mark 'apple' as invalid
-        ai.mark_invalid(['a', 'p', 'p', 'l', 'e']);
+        let opener = ['a', 'b', 'o', 'u', 't'];
+        let mut ai = HeuristicGuesser::new(wordlist).with_forced_opener(opener);
 
-        // Get candidates - should not include 'apple'
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 1);
-        assert!(candidates.contains(&['a', 'b', 'o', 'u', 't']));
+        // The opener leads and is not repeated among the ranked candidates
+        assert_eq!(ai.suggest_top_n(3), vec![opener, ['a', 'p', 'p', 'l', 'e']]);
+        assert_eq!(ai.suggest_top_n(1), vec![opener]);
+        assert!(ai.suggest_top_n(0).is_empty());
     }
 
     #[test]
-    fn test_heuristic_guesser_reset() {
-        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['h', 'e', 'l', 'l', 'o']];
+    fn test_heuristic_guesser_evaluate_guess() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+            ['s', 't', 'o', 'n', 'e'],
+        ];
         let mut ai = HeuristicGuesser::new(wordlist.clone());
 
-        // Update knowledge
-        ai.update(['a', 'p', 'p', 'l', 'e'], [LetterResult::Correct; 5]);
+        // The AI's own guess scores at least as well as any other word (up to the order
+        // the letter scores were summed in)
+        let best = ai.make_guess().unwrap();
+        let best_score = ai.evaluate_guess(&best).unwrap();
+        for word in &wordlist {
+            assert!(ai.evaluate_guess(word).unwrap() <= best_score + 1e-9);
+        }
+        // Words outside the word list can be rated too; letters no candidate has score nothing
+        assert_eq!(ai.evaluate_guess(&['x', 'y', 'z', 'x', 'y']), Some(0.0));
+
+        for word in &wordlist {
+            ai.mark_invalid(*word);
+        }
+        assert_eq!(ai.evaluate_guess(&best), None);
+    }
+
+    #[test]
+    fn test_heuristic_guesser_explain_guess() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+            ['s', 't', 'o', 'n', 'e'],
+        ];
+        let mut ai = HeuristicGuesser::new(wordlist);
 
-        // Mark word as invalid
-        ai.mark_invalid(['h', 'e', 'l', 'l', 'o']);
+        let lines = ai.explain_guess(&['a', 'p', 'p', 'l', 'e']);
+        assert!(lines[0].ends_with("bits over 4 candidates"));
+        // One line per distinct letter, those in half of the candidates first
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[1], "A: in 50% of the candidates (1.00 bits)");
+        assert_eq!(lines[4], "P: in 25% of the candidates (0.81 bits)");
 
-        // Reset
-        ai.reset();
+        for word in ai.tracker.candidates().to_vec() {
+            ai.mark_invalid(word);
+        }
+        assert!(ai.explain_guess(&['a', 'p', 'p', 'l', 'e']).is_empty());
+    }
 
-        // After reset, all words should be candidates again
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 2);
+    #[test]
+    fn test_heuristic_guesser_distinct_letters_only() {
+        let wordlist = wordle_core::Language::English.wordlist_array().to_vec();
+        let solution = *wordlist
+            .iter()
+            .find(|word| !has_distinct_letters(word))
+            .unwrap();
+        let mut ai = HeuristicGuesser::new(wordlist).with_distinct_letters_only();
+
+        // Repeated letters are only guessed once few candidates are left, so the solution
+        // is still found
+        for _ in 0..20 {
+            let many_left = ai.tracker.candidates().len() > crate::DISTINCT_LETTERS_CANDIDATES;
+            let guess = ai.make_guess().unwrap();
+            if many_left {
+                assert!(has_distinct_letters(&guess));
+            }
+            if guess == solution {
+                return;
+            }
+            ai.update(guess, wordle_core::take_guess(&solution, &guess));
+        }
+        panic!("the solution was not found");
     }
 }
@@ -0,0 +1,192 @@
+use crate::candidate_tracker::CandidateTracker;
+use crate::{WordleAI, knowledge::Knowledge};
+use std::collections::HashMap;
+use wordle_core::{LetterResult, has_distinct_letters};
+
+/// AI #6: Human-like Guesser
+///
+/// This strategy mimics how a typical person plays: it opens with a common word made of
+/// five different letters, and afterwards always guesses the most common word that still
+/// fits the feedback. It never plays a probe word that can't be the answer, which makes it
+/// a baseline for how far the optimal solvers are ahead of human play.
+#[derive(Clone, Debug)]
+pub struct HumanLikeGuesser {
+    /// The feedback so far and the words that still fit it
+    tracker: CandidateTracker,
+    /// Word frequencies, used to rank how common a word is
+    frequencies: HashMap<[char; 5], f64>,
+}
+
+impl HumanLikeGuesser {
+    /// Create a new HumanLikeGuesser without frequencies
+    ///
+    /// Every word is equally common then, so the earliest matching word in the list is guessed.
+    pub fn new(wordlist: Vec<[char; 5]>) -> Self {
+        Self::with_frequencies(wordlist, HashMap::new())
+    }
+
+    /// Create a new HumanLikeGuesser that ranks words by the given frequencies
+    ///
+    /// Words missing from `frequencies` are treated as having frequency 0.
+    pub fn with_frequencies(
+        wordlist: Vec<[char; 5]>,
+        frequencies: HashMap<[char; 5], f64>,
+    ) -> Self {
+        Self {
+            tracker: CandidateTracker::new(wordlist),
+            frequencies,
+        }
+    }
+
+    /// Always open with the given word instead of the strategy's own first guess
+    ///
+    /// Later guesses follow the normal strategy; `reset` brings the forced opener back.
+    pub fn with_forced_opener(mut self, word: [char; 5]) -> Self {
+        self.tracker.set_forced_opener(word);
+        self
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate
+    ///
+    /// The oldest feedback is dropped until some word matches again, see [`Knowledge::relax`].
+    pub fn with_lenient_mode(mut self) -> Self {
+        self.tracker.set_lenient();
+        self
+    }
+
+    /// Get the frequency of a word, or 0 if it is unknown
+    fn frequency(&self, word: &[char; 5]) -> f64 {
+        self.frequencies.get(word).copied().unwrap_or(0.0)
+    }
+
+    /// Pick the most common of the given words, preferring the earliest one on ties
+    fn most_common<'a>(&self, words: impl Iterator<Item = &'a [char; 5]>) -> Option<[char; 5]> {
+        words.copied().reduce(|best, word| {
+            if self.frequency(&word) > self.frequency(&best) {
+                word
+            } else {
+                best
+            }
+        })
+    }
+}
+
+impl WordleAI for HumanLikeGuesser {
+    fn make_guess(&mut self) -> Option<[char; 5]> {
+        if let Some(opener) = self.tracker.opener() {
+            return Some(opener);
+        }
+
+        self.tracker.relax_if_lenient();
+        let candidates = self.tracker.candidates();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        // Open with a common word without repeated letters, like most people do
+        if self.tracker.guessed_words().is_empty() {
+            let opener =
+                self.most_common(candidates.iter().filter(|word| has_distinct_letters(word)));
+            if opener.is_some() {
+                return opener;
+            }
+        }
+
+        self.most_common(candidates.iter())
+    }
+
+    fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
+        self.tracker.update(guess, result);
+    }
+
+    fn mark_invalid(&mut self, word: [char; 5]) {
+        self.tracker.mark_invalid(word);
+    }
+
+    fn reset(&mut self) {
+        self.tracker.reset();
+    }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Human-like Guesser"
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        Some(self.tracker.knowledge())
+    }
+
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        Some(self.tracker.candidates())
+    }
+
+    fn information_progress(&self) -> f64 {
+        self.tracker.information_progress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_human_like_guesser_opens_with_common_distinct_word() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+            ['s', 'h', 'i', 'n', 'y'],
+        ];
+        let frequencies = HashMap::from([
+            (['a', 'p', 'p', 'l', 'e'], 0.9),
+            (['a', 'b', 'o', 'u', 't'], 0.5),
+            (['h', 'e', 'l', 'l', 'o'], 0.8),
+            (['s', 'h', 'i', 'n', 'y'], 0.1),
+        ]);
+        let mut ai = HumanLikeGuesser::with_frequencies(wordlist, frequencies);
+
+        // 'apple' and 'hello' are more common, but repeat a letter
+        assert_eq!(ai.make_guess(), Some(['a', 'b', 'o', 'u', 't']));
+    }
+
+    #[test]
+    fn test_human_like_guesser_guesses_most_common_candidate() {
+        let wordlist = vec![
+            ['a', 'p', 'p', 'l', 'e'],
+            ['a', 'b', 'o', 'u', 't'],
+            ['a', 'l', 'o', 'f', 't'],
+            ['h', 'e', 'l', 'l', 'o'],
+        ];
+        let frequencies = HashMap::from([
+            (['a', 'b', 'o', 'u', 't'], 0.2),
+            (['a', 'l', 'o', 'f', 't'], 0.1),
+            (['h', 'e', 'l', 'l', 'o'], 0.9),
+        ]);
+        let mut ai = HumanLikeGuesser::with_frequencies(wordlist, frequencies);
+
+        let result = [
+            LetterResult::Correct,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+        ];
+        ai.update(['a', 'p', 'p', 'e', 'y'], result);
+
+        // 'hello' is the most common word, but no longer a candidate
+        assert_eq!(ai.make_guess(), Some(['a', 'b', 'o', 'u', 't']));
+    }
+
+    #[test]
+    fn test_human_like_guesser_without_frequencies() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let mut ai = HumanLikeGuesser::new(wordlist);
+
+        // The first word without repeated letters is the opener
+        assert_eq!(ai.make_guess(), Some(['a', 'b', 'o', 'u', 't']));
+    }
+}
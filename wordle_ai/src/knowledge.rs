@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use wordle_core::LetterResult;
+use wordle_core::{GuessAnalysis, LetterResult, display_upper};
 
 /// Knowledge base for tracking what we know about the hidden word
 #[derive(Clone, Debug)]
@@ -8,14 +8,34 @@ pub struct Knowledge {
     pub(crate) possible_letters: [HashSet<char>; 5],
     /// Letters that must appear in the word at least # times
     pub(crate) must_contain: HashMap<char, u8>,
+    /// Letters that may appear in the word at most # times
+    pub(crate) max_contain: HashMap<char, u8>,
     /// Letters that are fixed in certain positions
     pub(crate) fixed_positions: [bool; 5],
+    /// Every guess and its result that this knowledge was built from, oldest first
+    pub(crate) feedback: Vec<([char; 5], [LetterResult; 5])>,
+    /// Whether feedback had to be dropped because it contradicted the rest
+    pub(crate) relaxed: bool,
+    /// Every letter a word may consist of
+    pub(crate) alphabet: HashSet<char>,
+}
+
+impl Default for Knowledge {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Knowledge {
+    /// Knowledge about a word made of the letters a-z
     pub fn new() -> Self {
+        Self::with_alphabet('a'..='z')
+    }
+
+    /// Knowledge about a word made of the given letters, e.g. `Language::alphabet()`
+    pub fn with_alphabet(alphabet: impl IntoIterator<Item = char>) -> Self {
         // Initially, all positions can have any letter
-        let all_letters: HashSet<char> = ('a'..='z').collect();
+        let all_letters: HashSet<char> = alphabet.into_iter().collect();
         Self {
             possible_letters: [
                 all_letters.clone(),
@@ -25,25 +45,118 @@ impl Knowledge {
                 all_letters.clone(),
             ],
             must_contain: HashMap::new(),
+            max_contain: HashMap::new(),
             fixed_positions: [false; 5],
+            feedback: Vec::new(),
+            relaxed: false,
+            alphabet: all_letters,
         }
     }
 
-    /// Update knowledge based on a guess and its result
-    pub fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
-        // First: compute, per letter, how many Correct or Misplaced we have in this guess
-        let mut positive_counts: HashMap<char, u8> = HashMap::new();
-        for (&letter, &letter_result) in guess.iter().zip(result.iter()) {
-            match letter_result {
-                LetterResult::Correct | LetterResult::Misplaced => {
-                    positive_counts
-                        .entry(letter)
-                        .and_modify(|e| *e += 1)
-                        .or_insert(1);
-                }
-                LetterResult::Absent => { /* do nothing here */ }
+    /// Knowledge about a word from the given list, with the letters a-z and any other
+    /// letters the list uses, like the German umlauts and ß
+    pub fn for_wordlist(wordlist: &[[char; 5]]) -> Self {
+        Self::with_alphabet(('a'..='z').chain(wordlist.iter().flatten().copied()))
+    }
+
+    /// Every letter a word may consist of
+    pub fn alphabet(&self) -> &HashSet<char> {
+        &self.alphabet
+    }
+
+    /// Forget all feedback, keeping the alphabet
+    pub fn clear(&mut self) {
+        *self = Self::with_alphabet(std::mem::take(&mut self.alphabet));
+    }
+
+    /// For each position (0-4), which letters are still possible
+    pub fn possible_letters(&self) -> &[HashSet<char>; 5] {
+        &self.possible_letters
+    }
+
+    /// Letters that may appear in the word at most # times
+    ///
+    /// Only known for letters that were guessed more often than the word contains them.
+    pub fn max_contain(&self) -> &HashMap<char, u8> {
+        &self.max_contain
+    }
+
+    /// For each position (0-4), whether its letter is known for certain
+    pub fn fixed_positions(&self) -> &[bool; 5] {
+        &self.fixed_positions
+    }
+
+    /// Whether some feedback was dropped by [`Knowledge::relax`], i.e. the feedback seemed inconsistent
+    pub fn is_relaxed(&self) -> bool {
+        self.relaxed
+    }
+
+    /// Forget the oldest guess and rebuild the knowledge from the remaining ones
+    ///
+    /// Used when the feedback contradicts itself and no word matches anymore.
+    /// Returns `false` if there was no feedback left to drop.
+    pub fn relax(&mut self) -> bool {
+        if self.feedback.is_empty() {
+            return false;
+        }
+
+        let remaining = self.feedback.split_off(1);
+        self.clear();
+        for (guess, result) in remaining {
+            self.update(guess, result);
+        }
+        self.relaxed = true;
+        true
+    }
+
+    /// Require `letter` at `position` (0-4), like a Correct result would
+    ///
+    /// Constraints added directly are not part of the feedback, so [`Knowledge::relax`] drops them.
+    pub fn require_at(&mut self, position: usize, letter: char) {
+        self.possible_letters[position].clear();
+        self.possible_letters[position].insert(letter);
+        self.fixed_positions[position] = true;
+
+        let fixed_count = (0..5)
+            .filter(|&pos| {
+                self.fixed_positions[pos] && self.possible_letters[pos].contains(&letter)
+            })
+            .count() as u8;
+        let count = self.must_contain.entry(letter).or_insert(0);
+        *count = (*count).max(fixed_count);
+    }
+
+    /// Require `letter` somewhere in the word
+    pub fn require(&mut self, letter: char) {
+        self.require_times(letter, 1);
+    }
+
+    /// Require `letter` at least `times` times in the word
+    pub fn require_times(&mut self, letter: char, times: u8) {
+        let count = self.must_contain.entry(letter).or_insert(0);
+        *count = (*count).max(times);
+    }
+
+    /// Rule out `letter` at `position` (0-4)
+    pub fn exclude_at(&mut self, position: usize, letter: char) {
+        self.possible_letters[position].remove(&letter);
+    }
+
+    /// Rule out `letter` everywhere except at fixed positions, like an Absent result would
+    pub fn exclude(&mut self, letter: char) {
+        for pos in 0..5 {
+            if !self.fixed_positions[pos] {
+                self.possible_letters[pos].remove(&letter);
             }
         }
+    }
+
+    /// Update knowledge based on a guess and its result
+    pub fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
+        self.feedback.push((guess, result));
+
+        // First: deduce, per letter, how many times it appears according to this guess alone
+        let letter_counts = GuessAnalysis::new(&guess, result).letter_counts;
 
         // Now handle positional constraints
         for (position, (&letter, &letter_result)) in guess.iter().zip(result.iter()).enumerate() {
@@ -64,9 +177,8 @@ impl Knowledge {
                     // If this letter had ZERO positive hits in this guess, it truly doesn't appear in the word.
                     // Remove it from all non-fixed positions in that case.
                     // If it *did* have positive hits, then this absent only means "not at this position"
-                    // (the total occurrences are limited to positive_counts[letter]).
-                    let pos_count = *positive_counts.get(&letter).unwrap_or(&0);
-                    if pos_count == 0 {
+                    // (the total occurrences are limited below).
+                    if letter_counts[&letter].0 == 0 {
                         for pos in 0..5 {
                             if !self.fixed_positions[pos] {
                                 self.possible_letters[pos].remove(&letter);
@@ -80,14 +192,21 @@ impl Knowledge {
             }
         }
 
-        // Finally, update must_contain to be the max of previously known and what we observed in this guess
-        for (&letter, &count) in &positive_counts {
+        // Finally, tighten the known counts of every letter that had positive hits
+        for (&letter, &(min, max)) in &letter_counts {
+            if min == 0 {
+                continue;
+            }
             self.must_contain
                 .entry(letter)
-                .and_modify(|prev| {
-                    *prev = (*prev).max(count);
-                })
-                .or_insert(count);
+                .and_modify(|prev| *prev = (*prev).max(min))
+                .or_insert(min);
+            if let Some(max) = max {
+                self.max_contain
+                    .entry(letter)
+                    .and_modify(|prev| *prev = (*prev).min(max))
+                    .or_insert(max);
+            }
         }
     }
 
@@ -108,6 +227,453 @@ impl Knowledge {
             }
         }
 
+        // Check that no letter appears more often than allowed
+        for (&letter, &count) in &self.max_contain {
+            let actual_count = word.iter().filter(|&&c| c == letter).count() as u8;
+            if actual_count > count {
+                return false;
+            }
+        }
+
         true
     }
+
+    /// Explain in human-readable form why a guess's result can't be right given what we know
+    ///
+    /// Catches feedback that no word could produce together with the earlier feedback, like a
+    /// letter marked green at a position that is already known to hold another letter, or
+    /// marked green after having been absent. Returns `None` if the feedback is consistent.
+    pub fn find_contradiction(
+        &self,
+        guess: [char; 5],
+        result: [LetterResult; 5],
+    ) -> Option<String> {
+        // A Correct result overrides the position's constraints, so check it against them first
+        for (position, (&letter, &letter_result)) in guess.iter().zip(result.iter()).enumerate() {
+            let possible = &self.possible_letters[position];
+            if letter_result != LetterResult::Correct || possible.contains(&letter) {
+                continue;
+            }
+
+            return Some(
+                if self.fixed_positions[position]
+                    && let Some(&fixed) = possible.iter().next()
+                {
+                    format!(
+                        "position {} is already known to be {}, not {}",
+                        position + 1,
+                        display_upper(fixed),
+                        display_upper(letter)
+                    )
+                } else if self.possible_letters.iter().all(|p| !p.contains(&letter)) {
+                    format!(
+                        "{} was marked absent before, but is green now",
+                        display_upper(letter)
+                    )
+                } else {
+                    format!(
+                        "{} was ruled out at position {} before, but is green there now",
+                        display_upper(letter),
+                        position + 1
+                    )
+                },
+            );
+        }
+
+        let mut updated = self.clone();
+        updated.update(guess, result);
+
+        let mut must_contain: Vec<(&char, &u8)> = updated.must_contain.iter().collect();
+        must_contain.sort();
+        for (&letter, &count) in must_contain {
+            if let Some(&max) = updated.max_contain.get(&letter)
+                && max < count
+            {
+                return Some(format!(
+                    "{} must appear at least {}, but at most {}",
+                    display_upper(letter),
+                    times(count),
+                    times(max)
+                ));
+            }
+
+            let positions = updated
+                .possible_letters
+                .iter()
+                .filter(|p| p.contains(&letter))
+                .count() as u8;
+            if positions == 0 {
+                return Some(format!(
+                    "{} is in the word, but ruled out at every position",
+                    display_upper(letter)
+                ));
+            } else if positions < count {
+                return Some(format!(
+                    "{} must appear {}, but fits only {} positions",
+                    display_upper(letter),
+                    times(count),
+                    positions
+                ));
+            }
+        }
+
+        if updated
+            .must_contain
+            .values()
+            .map(|&count| count as usize)
+            .sum::<usize>()
+            > 5
+        {
+            return Some("more than five letters are known to be in the word".to_string());
+        }
+
+        None
+    }
+
+    /// Explain in human-readable form why a word doesn't match our current knowledge
+    ///
+    /// Returns one reason per violated constraint, or an empty list if the word matches.
+    pub fn explain_mismatch(&self, word: &[char; 5]) -> Vec<String> {
+        let mut reasons = Vec::new();
+
+        // Check that each position has a valid letter
+        for (position, &letter) in word.iter().enumerate() {
+            let possible = &self.possible_letters[position];
+            if possible.contains(&letter) {
+                continue;
+            }
+
+            if self.fixed_positions[position]
+                && let Some(&fixed) = possible.iter().next()
+            {
+                reasons.push(format!(
+                    "position {} must be {}",
+                    position + 1,
+                    display_upper(fixed)
+                ));
+            } else if self.possible_letters.iter().all(|p| !p.contains(&letter)) {
+                reasons.push(format!("contains absent letter {}", display_upper(letter)));
+            } else {
+                reasons.push(format!(
+                    "position {} can't be {}",
+                    position + 1,
+                    display_upper(letter)
+                ));
+            }
+        }
+
+        // Check that all must-contain letters are present
+        let mut must_contain: Vec<(&char, &u8)> = self.must_contain.iter().collect();
+        must_contain.sort();
+        for (&letter, &count) in must_contain {
+            let actual_count = word.iter().filter(|&&c| c == letter).count() as u8;
+            if actual_count < count {
+                reasons.push(if count == 1 {
+                    format!("must contain {}", display_upper(letter))
+                } else {
+                    format!(
+                        "must contain {} at least {} times",
+                        display_upper(letter),
+                        count
+                    )
+                });
+            }
+        }
+
+        // Check that no letter appears more often than allowed
+        let mut max_contain: Vec<(&char, &u8)> = self.max_contain.iter().collect();
+        max_contain.sort();
+        for (&letter, &count) in max_contain {
+            let actual_count = word.iter().filter(|&&c| c == letter).count() as u8;
+            if actual_count > count {
+                reasons.push(if count == 1 {
+                    format!("can contain {} only once", display_upper(letter))
+                } else {
+                    format!(
+                        "can contain {} at most {} times",
+                        display_upper(letter),
+                        count
+                    )
+                });
+            }
+        }
+
+        reasons
+    }
+}
+
+/// A letter count in words: "once", "twice" or "N times"
+fn times(count: u8) -> String {
+    match count {
+        0 => "zero times".to_string(),
+        1 => "once".to_string(),
+        2 => "twice".to_string(),
+        _ => format!("{} times", count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_core::take_guess;
+    use wordle_core::test_util::all_words;
+
+    fn word(s: &str) -> [char; 5] {
+        s.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_take_guess_triple_letter_guess() {
+        use LetterResult::*;
+
+        // Two of the three b's are in the solution, one of them in place
+        assert_eq!(
+            take_guess(&word("abbey"), &word("bobby")),
+            [Misplaced, Absent, Correct, Absent, Correct]
+        );
+        // Only one b is in the solution
+        assert_eq!(
+            take_guess(&word("rebus"), &word("bobby")),
+            [Absent, Absent, Correct, Absent, Absent]
+        );
+    }
+
+    #[test]
+    fn test_knowledge_caps_letter_count() {
+        let solution = word("rebus");
+        let guess = word("bobby");
+        let mut knowledge = Knowledge::new();
+        knowledge.update(guess, take_guess(&solution, &guess));
+
+        assert!(knowledge.matches(&solution));
+        // A second b fits every position constraint, but the solution has only one
+        assert!(!knowledge.matches(&word("xbbxx")));
+        assert_eq!(
+            knowledge.explain_mismatch(&word("xbbxx")),
+            vec!["can contain B only once"]
+        );
+    }
+
+    #[test]
+    fn test_knowledge_caps_letter_count_with_repeated_hits() {
+        let solution = word("abbey");
+        let guess = word("bobby");
+        let mut knowledge = Knowledge::new();
+        knowledge.update(guess, take_guess(&solution, &guess));
+
+        assert!(knowledge.matches(&solution));
+        assert_eq!(knowledge.must_contain.get(&'b'), Some(&2));
+        assert_eq!(knowledge.max_contain.get(&'b'), Some(&2));
+    }
+
+    #[test]
+    fn test_knowledge_caps_letter_count_from_triple_guess() {
+        let solution = word("those");
+        let guess = word("geese");
+        let mut knowledge = Knowledge::new();
+        knowledge.update(guess, take_guess(&solution, &guess));
+
+        assert!(knowledge.matches(&solution));
+        // The e at the start is allowed by position, but makes two e's
+        assert!(!knowledge.matches(&word("ehose")));
+    }
+
+    #[test]
+    fn test_knowledge_direct_constraints() {
+        let mut knowledge = Knowledge::new();
+        knowledge.require_at(0, 'a');
+        knowledge.require('e');
+        knowledge.exclude_at(4, 'e');
+        for letter in ['r', 's', 't'] {
+            knowledge.exclude(letter);
+        }
+
+        assert!(knowledge.matches(&word("ahead")));
+        assert!(knowledge.matches(&word("angel")));
+        // Wrong first letter
+        assert!(!knowledge.matches(&word("bagel")));
+        // No e
+        assert!(!knowledge.matches(&word("album")));
+        // e only at the excluded position
+        assert!(!knowledge.matches(&word("alive")));
+        // Absent letter
+        assert!(!knowledge.matches(&word("arena")));
+    }
+
+    #[test]
+    fn test_knowledge_require_at_counts_repeated_letters() {
+        let mut knowledge = Knowledge::new();
+        knowledge.require_at(1, 'e');
+        knowledge.require_at(2, 'e');
+
+        assert_eq!(knowledge.must_contain.get(&'e'), Some(&2));
+        assert!(knowledge.matches(&word("geese")));
+        // Excluding a fixed letter elsewhere keeps the fixed positions
+        knowledge.exclude('e');
+        assert!(knowledge.matches(&word("seeds")));
+        assert!(!knowledge.matches(&word("geese")));
+    }
+
+    #[test]
+    fn test_knowledge_require_times() {
+        let mut knowledge = Knowledge::new();
+        knowledge.require_times('e', 2);
+        assert!(knowledge.matches(&word("geese")));
+        assert!(knowledge.matches(&word("eerie")));
+        assert!(!knowledge.matches(&word("bagel")));
+
+        // Requiring a letter once more doesn't lower the count
+        knowledge.require('e');
+        assert!(!knowledge.matches(&word("bagel")));
+    }
+
+    #[test]
+    fn test_knowledge_never_eliminates_solution() {
+        // Exhaustive over a small alphabet, so every combination of repeated letters
+        // in solution and guess is covered
+        let words = all_words(&['a', 'b', 'c']);
+        for solution in &words {
+            for guess in &words {
+                let mut knowledge = Knowledge::new();
+                knowledge.update(*guess, take_guess(solution, guess));
+                assert!(
+                    knowledge.matches(solution),
+                    "{:?} eliminated by guess {:?}",
+                    solution,
+                    guess
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_knowledge_never_eliminates_solution_after_several_guesses() {
+        let words = all_words(&['a', 'b', 'c']);
+        let guesses: Vec<[char; 5]> = words.iter().copied().step_by(7).collect();
+        for solution in &words {
+            let mut knowledge = Knowledge::new();
+            for guess in &guesses {
+                knowledge.update(*guess, take_guess(solution, guess));
+            }
+            assert!(knowledge.matches(solution), "{:?} eliminated", solution);
+        }
+    }
+
+    #[test]
+    fn test_knowledge_no_cap_without_absent_repeat() {
+        let mut knowledge = Knowledge::new();
+        knowledge.update(
+            word("ebbxy"),
+            [
+                LetterResult::Misplaced,
+                LetterResult::Absent,
+                LetterResult::Absent,
+                LetterResult::Absent,
+                LetterResult::Absent,
+            ],
+        );
+
+        // Only one e was guessed, so the word may still contain more of them
+        assert!(knowledge.matches(&word("seeee")));
+    }
+
+    #[test]
+    fn test_find_contradiction() {
+        use LetterResult::*;
+
+        let mut knowledge = Knowledge::new();
+        knowledge.update(word("crane"), [Correct, Absent, Absent, Absent, Misplaced]);
+
+        // Real feedback is never contradictory
+        let solution = word("chest");
+        assert_eq!(
+            knowledge.find_contradiction(word("chest"), take_guess(&solution, &word("chest"))),
+            None
+        );
+        assert_eq!(
+            knowledge.find_contradiction(word("cello"), take_guess(&solution, &word("cello"))),
+            None
+        );
+
+        // Green for a letter that was absent
+        assert_eq!(
+            knowledge.find_contradiction(word("plant"), [Absent, Absent, Correct, Absent, Absent]),
+            Some("A was marked absent before, but is green now".to_string())
+        );
+        // Green for another letter at a known position
+        assert_eq!(
+            knowledge.find_contradiction(word("shelf"), [Correct, Absent, Absent, Absent, Absent]),
+            Some("position 1 is already known to be C, not S".to_string())
+        );
+        // Green at a position the letter was yellow at
+        assert_eq!(
+            knowledge.find_contradiction(word("those"), [Absent, Absent, Absent, Absent, Correct]),
+            Some("E was ruled out at position 5 before, but is green there now".to_string())
+        );
+        // The e is in the word, so it can't be absent now
+        assert_eq!(
+            knowledge.find_contradiction(word("celeb"), [Correct, Absent, Absent, Absent, Absent]),
+            Some("E is in the word, but ruled out at every position".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_contradiction_letter_count() {
+        use LetterResult::*;
+
+        // Only one b, since the other b's were absent
+        let mut knowledge = Knowledge::new();
+        knowledge.update(word("bobby"), [Absent, Absent, Correct, Absent, Absent]);
+
+        assert_eq!(
+            knowledge
+                .find_contradiction(word("abbot"), [Absent, Misplaced, Correct, Absent, Absent]),
+            Some("B must appear at least twice, but at most once".to_string())
+        );
+        assert_eq!(
+            knowledge.find_contradiction(word("abbot"), [Absent, Absent, Correct, Absent, Absent]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_contradiction_no_position_left() {
+        use LetterResult::*;
+
+        let mut knowledge = Knowledge::new();
+        knowledge.update(word("aback"), [Absent, Absent, Absent, Absent, Absent]);
+        knowledge.update(word("those"), [Correct, Correct, Correct, Correct, Absent]);
+
+        // Only the last position is open, but the y was ruled out there
+        knowledge.exclude_at(4, 'y');
+        assert_eq!(
+            knowledge
+                .find_contradiction(word("yield"), [Misplaced, Absent, Absent, Absent, Absent]),
+            Some("Y is in the word, but ruled out at every position".to_string())
+        );
+    }
+
+    #[test]
+    fn test_knowledge_with_umlauts() {
+        let wordlist = [word("blüte"), word("blute"), word("außen")];
+        let solution = word("blüte");
+        let guess = word("blute");
+
+        // Plain a-z knowledge can never match a word with an umlaut
+        let mut knowledge = Knowledge::new();
+        knowledge.update(guess, take_guess(&solution, &guess));
+        assert!(!knowledge.matches(&solution));
+
+        let mut knowledge = Knowledge::for_wordlist(&wordlist);
+        assert!(knowledge.alphabet().contains(&'ü'));
+        assert!(knowledge.alphabet().contains(&'ß'));
+        knowledge.update(guess, take_guess(&solution, &guess));
+        assert!(knowledge.matches(&solution));
+        assert!(!knowledge.matches(&guess));
+
+        // Clearing keeps the alphabet
+        knowledge.clear();
+        assert!(knowledge.alphabet().contains(&'ü'));
+        assert!(knowledge.possible_letters()[2].contains(&'ü'));
+    }
 }
@@ -10,6 +10,14 @@ pub trait WordleAI {
     /// Returns `Some([char; 5])` with the next guess, or `None` if no more guesses are available
     fn make_guess(&mut self) -> Option<[char; 5]>;
 
+    /// Suggest up to `n` guesses, best first
+    ///
+    /// The first suggestion is the guess `make_guess` would make. By default, that is the
+    /// only one; strategies that rank their candidates return the next-best ones as well.
+    fn suggest_top_n(&mut self, n: usize) -> Vec<[char; 5]> {
+        self.make_guess().into_iter().take(n).collect()
+    }
+
     /// Update the AI's internal state based on the result of the previous guess
     ///
     /// # Arguments
@@ -28,15 +36,228 @@ pub trait WordleAI {
 
     /// Reset the AI to its initial state for a new game
     fn reset(&mut self);
+
+    /// Clone the AI with everything it has learned so far, as a boxed trait object
+    ///
+    /// This is what makes `Box<dyn WordleAI>` cloneable, e.g. to snapshot a solver before
+    /// an update. Implementations return `Box::new(self.clone())`.
+    fn box_clone(&self) -> Box<dyn WordleAI>;
+
+    /// Human-readable name of the strategy, e.g. to label a boxed solver
+    fn name(&self) -> &'static str;
+
+    /// Get what the AI has deduced about the hidden word so far
+    ///
+    /// Returns `None` for strategies that don't track feedback.
+    fn knowledge(&self) -> Option<&Knowledge> {
+        None
+    }
+
+    /// Get the words that could still be the solution, given the feedback so far
+    ///
+    /// Guessed words and words marked invalid are left out. Returns `None` for strategies
+    /// that don't track feedback.
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        None
+    }
+
+    /// How much of the information needed to find the solution has been gathered, from 0 to 1
+    ///
+    /// Computed as `1 - log2(remaining candidates) / log2(initial candidates)`, so every
+    /// halving of the candidates adds the same amount. Returns 0 for strategies that don't
+    /// track feedback.
+    fn information_progress(&self) -> f64 {
+        0.0
+    }
+
+    /// Explain why a word is no longer a candidate
+    ///
+    /// Returns human-readable reasons derived from the AI's knowledge, such as
+    /// "position 1 must be A", or `None` if the word still matches (or the AI
+    /// doesn't track knowledge at all).
+    ///
+    /// # Arguments
+    /// * `word` - The word to explain
+    fn explain_elimination(&self, word: &[char; 5]) -> Option<Vec<String>> {
+        let reasons = self.knowledge()?.explain_mismatch(word);
+        (!reasons.is_empty()).then_some(reasons)
+    }
+
+    /// Explain how the strategy rates a word as the next guess, e.g. why it recommends it
+    ///
+    /// Returns human-readable lines from the strategy's own scoring, such as the letter
+    /// frequencies it weighs. Empty for strategies that don't score guesses, or if no
+    /// candidate is left.
+    fn explain_guess(&self, _word: &[char; 5]) -> Vec<String> {
+        Vec::new()
+    }
 }
 
+/// [`WordleAI::information_progress`] for the given numbers of candidates, 1 once at most
+/// one candidate is left
+pub(crate) fn information_gathered(initial: usize, remaining: usize) -> f64 {
+    if remaining <= 1 || initial <= 1 {
+        return 1.0;
+    }
+    (1.0 - (remaining as f64).log2() / (initial as f64).log2()).clamp(0.0, 1.0)
+}
+
+impl Clone for Box<dyn WordleAI> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+mod anagrams;
+mod candidate_tracker;
+mod decision_tree;
+mod distinct_letters;
+mod elimination_guesser;
 mod entropy_guesser;
 mod heuristic_guesser;
+mod human_like_guesser;
 mod knowledge;
+mod partition;
+mod play;
 mod random_guesser;
 mod random_with_updates;
+mod review;
 
+pub use anagrams::group_anagrams;
+pub use decision_tree::{DecisionTree, build_decision_tree};
+pub use distinct_letters::DISTINCT_LETTERS_CANDIDATES;
+pub use elimination_guesser::EliminationGuesser;
 pub use entropy_guesser::EntropyGuesser;
-pub use heuristic_guesser::HeuristicGuesser;
+pub use heuristic_guesser::{DOUBLE_LETTER_CANDIDATES, HeuristicGuesser};
+pub use human_like_guesser::HumanLikeGuesser;
+pub use knowledge::Knowledge;
+pub use partition::partition_candidates;
+pub use play::{MAX_INVALID_GUESSES, PlayOutcome, play};
 pub use random_guesser::RandomGuesser;
 pub use random_with_updates::RandomWithUpdates;
+pub use review::{GuessReview, REVIEW_SAMPLE_LIMIT, review_guesses};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_core::{Language, take_guess};
+
+    #[test]
+    fn test_box_clone_mid_game() {
+        let wordlist: Vec<[char; 5]> = Language::English.wordlist_array()[..300].to_vec();
+        let solution = wordlist[150];
+        let agents: Vec<Box<dyn WordleAI>> = vec![
+            Box::new(RandomGuesser::with_seed(wordlist.clone(), 7)),
+            Box::new(RandomWithUpdates::with_seed(wordlist.clone(), 7)),
+            Box::new(HeuristicGuesser::new(wordlist.clone())),
+            Box::new(EntropyGuesser::new(wordlist.clone())),
+            Box::new(EliminationGuesser::new(wordlist.clone())),
+            Box::new(HumanLikeGuesser::new(wordlist.clone())),
+        ];
+
+        for mut ai in agents {
+            let guess = ai.make_guess().unwrap();
+            ai.update(guess, take_guess(&solution, &guess));
+
+            let mut clone = ai.clone();
+            assert_eq!(clone.make_guess(), ai.make_guess());
+            assert_eq!(clone.name(), ai.name());
+        }
+    }
+
+    /// The agents that track feedback, each forced to open with `opener`
+    fn tracking_agents(wordlist: &[[char; 5]], opener: [char; 5]) -> Vec<Box<dyn WordleAI>> {
+        vec![
+            Box::new(
+                RandomWithUpdates::with_seed(wordlist.to_vec(), 42).with_forced_opener(opener),
+            ),
+            Box::new(HeuristicGuesser::new(wordlist.to_vec()).with_forced_opener(opener)),
+            Box::new(EntropyGuesser::new(wordlist.to_vec()).with_forced_opener(opener)),
+            Box::new(EliminationGuesser::new(wordlist.to_vec()).with_forced_opener(opener)),
+            Box::new(HumanLikeGuesser::new(wordlist.to_vec()).with_forced_opener(opener)),
+        ]
+    }
+
+    #[test]
+    fn test_forced_opener() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let opener = ['s', 'h', 'i', 'n', 'y'];
+
+        for mut ai in tracking_agents(&wordlist, opener) {
+            // The opener is used first, even though it is not in the word list
+            assert_eq!(ai.make_guess(), Some(opener), "{}", ai.name());
+
+            // Afterwards the normal strategy takes over
+            ai.update(opener, [LetterResult::Absent; 5]);
+            let guess = ai.make_guess().unwrap();
+            assert!(wordlist.contains(&guess), "{}", ai.name());
+
+            // Resetting restores the forced opener
+            ai.reset();
+            assert_eq!(ai.make_guess(), Some(opener), "{}", ai.name());
+        }
+    }
+
+    #[test]
+    fn test_never_repeats_guess() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
+        let guess = ['a', 'p', 'p', 'l', 'e'];
+
+        for mut ai in tracking_agents(&wordlist, guess) {
+            // A mistaken all-green entry fixes every position, so the contradicting all-absent
+            // entry afterwards cannot remove any letter and the knowledge still matches 'apple'
+            ai.update(guess, [LetterResult::Correct; 5]);
+            ai.update(guess, [LetterResult::Absent; 5]);
+
+            // The guessed word must still never be suggested again, not even as the opener
+            assert!(
+                !ai.remaining_candidates().unwrap().contains(&guess),
+                "{}",
+                ai.name()
+            );
+            assert_ne!(ai.make_guess(), Some(guess), "{}", ai.name());
+        }
+    }
+
+    #[test]
+    fn test_names_are_distinct() {
+        let wordlist: Vec<[char; 5]> = Language::English.wordlist_array()[..10].to_vec();
+        let agents: Vec<Box<dyn WordleAI>> = vec![
+            Box::new(RandomGuesser::new(wordlist.clone())),
+            Box::new(RandomWithUpdates::new(wordlist.clone())),
+            Box::new(HeuristicGuesser::new(wordlist.clone())),
+            Box::new(EntropyGuesser::new(wordlist.clone())),
+            Box::new(EliminationGuesser::new(wordlist.clone())),
+            Box::new(HumanLikeGuesser::new(wordlist)),
+        ];
+
+        let names: std::collections::HashSet<&str> = agents.iter().map(|ai| ai.name()).collect();
+        assert_eq!(names.len(), agents.len());
+    }
+
+    #[test]
+    fn test_information_progress() {
+        assert_eq!(information_gathered(1024, 1024), 0.0);
+        assert_eq!(information_gathered(1024, 32), 0.5);
+        assert_eq!(information_gathered(1024, 1), 1.0);
+        assert_eq!(information_gathered(1024, 0), 1.0);
+
+        let wordlist = Language::English.wordlist_array().to_vec();
+        let solution = wordlist[100];
+        let mut ai = HeuristicGuesser::new(wordlist);
+        assert_eq!(ai.information_progress(), 0.0);
+
+        let mut progress = 0.0;
+        loop {
+            let guess = ai.make_guess().unwrap();
+            if guess == solution {
+                break;
+            }
+            ai.update(guess, take_guess(&solution, &guess));
+            assert!(ai.information_progress() >= progress);
+            progress = ai.information_progress();
+        }
+        assert!(progress > 0.0);
+        assert_eq!(RandomGuesser::new(Vec::new()).information_progress(), 0.0);
+    }
+}
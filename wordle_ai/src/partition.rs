@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use wordle_core::LetterResult;
+
+/// Group the candidates by the feedback pattern a guess would produce against each of them
+///
+/// Every key is a pattern that at least one candidate produces, so there are at most 243
+/// entries. Within each group, candidates keep their original order. This is the building
+/// block for lookahead and decision trees: after playing `guess` and receiving a pattern,
+/// the remaining candidates are exactly that pattern's group.
+///
+/// # Arguments
+/// * `guess` - The word that would be guessed
+/// * `candidates` - The words that could still be the solution
+pub fn partition_candidates(
+    guess: &[char; 5],
+    candidates: &[[char; 5]],
+) -> HashMap<[LetterResult; 5], Vec<[char; 5]>> {
+    let mut partition: HashMap<[LetterResult; 5], Vec<[char; 5]>> = HashMap::new();
+
+    for &candidate in candidates {
+        let pattern = wordle_core::take_guess(&candidate, guess);
+        partition.entry(pattern).or_default().push(candidate);
+    }
+
+    partition
+}
+
+/// Count how many candidates would produce each feedback pattern for a guess
+///
+/// Like [`partition_candidates`], but without collecting the words themselves.
+pub(crate) fn pattern_counts(
+    guess: &[char; 5],
+    candidates: &[[char; 5]],
+) -> HashMap<[LetterResult; 5], usize> {
+    let mut pattern_counts: HashMap<[LetterResult; 5], usize> = HashMap::new();
+
+    for &candidate in candidates {
+        let pattern = wordle_core::take_guess(&candidate, guess);
+        *pattern_counts.entry(pattern).or_insert(0) += 1;
+    }
+
+    pattern_counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LetterResult::{Absent, Correct, Misplaced};
+    use wordle_core::Language;
+
+    #[test]
+    fn test_partition_candidates() {
+        let candidates = vec![
+            ['c', 'r', 'a', 'n', 'e'],
+            ['c', 'r', 'a', 't', 'e'],
+            ['t', 'r', 'a', 'c', 'e'],
+            ['h', 'o', 'u', 's', 'e'],
+        ];
+        let partition = partition_candidates(&['c', 'r', 'a', 'n', 'e'], &candidates);
+
+        assert_eq!(partition.len(), 4);
+        assert_eq!(partition[&[Correct; 5]], vec![['c', 'r', 'a', 'n', 'e']]);
+        assert_eq!(
+            partition[&[Correct, Correct, Correct, Absent, Correct]],
+            vec![['c', 'r', 'a', 't', 'e']]
+        );
+        assert_eq!(
+            partition[&[Misplaced, Correct, Correct, Absent, Correct]],
+            vec![['t', 'r', 'a', 'c', 'e']]
+        );
+        assert_eq!(
+            partition[&[Absent, Absent, Absent, Absent, Correct]],
+            vec![['h', 'o', 'u', 's', 'e']]
+        );
+    }
+
+    #[test]
+    fn test_partition_matches_pattern_counts() {
+        let candidates = Language::English.wordlist_array();
+        let guess = ['s', 'l', 'a', 't', 'e'];
+
+        let partition = partition_candidates(&guess, candidates);
+        let counts = pattern_counts(&guess, candidates);
+
+        assert_eq!(partition.len(), counts.len());
+        for (pattern, words) in &partition {
+            assert_eq!(words.len(), counts[pattern]);
+            for word in words {
+                assert_eq!(&wordle_core::take_guess(word, &guess), pattern);
+            }
+        }
+        let total: usize = partition.values().map(Vec::len).sum();
+        assert_eq!(total, candidates.len());
+    }
+}
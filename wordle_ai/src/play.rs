@@ -0,0 +1,198 @@
+use crate::WordleAI;
+use wordle_core::{Game, GameError, GuessResult, LetterResult};
+
+/// How many words the game may reject before the AI is considered stuck
+pub const MAX_INVALID_GUESSES: usize = 100;
+
+/// The result of letting an AI play a full game
+#[derive(Debug, Clone)]
+pub struct PlayOutcome {
+    /// Whether the AI found the solution within the allowed attempts
+    pub solved: bool,
+    /// Every accepted guess together with its feedback, in order
+    pub guesses: Vec<([char; 5], [LetterResult; 5])>,
+}
+
+/// Let an AI play a game until it is won, lost, or the AI runs out of suggestions
+///
+/// Words rejected by the game as not in its word list are marked invalid on the AI
+/// and do not count as guesses. To guarantee termination even with a misbehaving AI,
+/// the game is counted as lost once its attempts are used up or more than
+/// [`MAX_INVALID_GUESSES`] words have been rejected.
+///
+/// # Arguments
+/// * `ai` - The AI making the guesses
+/// * `game` - The game to play
+pub fn play(ai: &mut dyn WordleAI, game: &mut Game) -> PlayOutcome {
+    let mut guesses = Vec::new();
+    let mut invalid_guesses = 0;
+
+    loop {
+        if !game.has_attempts_left() || invalid_guesses > MAX_INVALID_GUESSES {
+            return PlayOutcome {
+                solved: false,
+                guesses,
+            };
+        }
+
+        let Some(guess) = ai.make_guess() else {
+            return PlayOutcome {
+                solved: false,
+                guesses,
+            };
+        };
+
+        match game.take_guess(&guess) {
+            Ok(GuessResult::Won(result)) => {
+                guesses.push((guess, result));
+                return PlayOutcome {
+                    solved: true,
+                    guesses,
+                };
+            }
+            Ok(GuessResult::Lost { last_guess, .. }) => {
+                guesses.push((guess, last_guess));
+                return PlayOutcome {
+                    solved: false,
+                    guesses,
+                };
+            }
+            Ok(GuessResult::Continue(result)) => {
+                ai.update(guess, result);
+                guesses.push((guess, result));
+            }
+            Err(GameError::WordNotInList | GameError::ViolatesHardMode) => {
+                // Make sure the AI never suggests this word again, it can't be played in this game
+                ai.mark_invalid(guess);
+                invalid_guesses += 1;
+            }
+            Err(GameError::GameOver | GameError::WrongLength | GameError::InvalidChar(_)) => {
+                // Only happens if the game was already over before the AI started, since
+                // guesses are never typed input
+                return PlayOutcome {
+                    solved: false,
+                    guesses,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HeuristicGuesser;
+    use std::collections::VecDeque;
+    use wordle_core::Language;
+
+    #[test]
+    fn test_play_records_every_guess() {
+        let language = Language::English;
+        let mut game = Game::new(6, language).unwrap();
+        let mut ai = HeuristicGuesser::new(language.wordlist_array().to_vec());
+
+        let outcome = play(&mut ai, &mut game);
+
+        assert_eq!(outcome.guesses.len(), game.attempts());
+        assert!(outcome.guesses.len() <= game.max_attempts());
+        let last_all_correct = outcome
+            .guesses
+            .last()
+            .is_some_and(|(_, result)| result.iter().all(|&r| r == LetterResult::Correct));
+        assert_eq!(outcome.solved, last_all_correct);
+    }
+
+    #[test]
+    fn test_play_skips_words_not_in_list() {
+        let language = Language::English;
+        let mut game = Game::new(6, language).unwrap();
+        let valid_word = language.wordlist_array()[0];
+        let mut ai = ScriptedAI::new(vec![['z', 'z', 'z', 'z', 'z'], valid_word]);
+
+        let outcome = play(&mut ai, &mut game);
+
+        // The rejected word is marked invalid and only the valid word counts as a guess
+        assert_eq!(ai.invalid_words, vec![['z', 'z', 'z', 'z', 'z']]);
+        assert_eq!(outcome.guesses.len(), 1);
+        assert_eq!(outcome.guesses[0].0, valid_word);
+        assert_eq!(game.attempts(), 1);
+    }
+
+    #[test]
+    fn test_play_terminates_with_only_invalid_words() {
+        let mut game = Game::new(6, Language::English).unwrap();
+        let mut ai = AlwaysInvalidAI { rejected: 0 };
+
+        let outcome = play(&mut ai, &mut game);
+
+        assert!(!outcome.solved);
+        assert!(outcome.guesses.is_empty());
+        assert_eq!(ai.rejected, MAX_INVALID_GUESSES + 1);
+    }
+
+    /// Test AI that ignores `mark_invalid` and keeps suggesting a word that is not in any list
+    #[derive(Clone)]
+    struct AlwaysInvalidAI {
+        rejected: usize,
+    }
+
+    impl WordleAI for AlwaysInvalidAI {
+        fn make_guess(&mut self) -> Option<[char; 5]> {
+            Some(['z', 'z', 'z', 'z', 'z'])
+        }
+
+        fn update(&mut self, _guess: [char; 5], _result: [LetterResult; 5]) {}
+
+        fn mark_invalid(&mut self, _word: [char; 5]) {
+            self.rejected += 1;
+        }
+
+        fn reset(&mut self) {}
+
+        fn box_clone(&self) -> Box<dyn WordleAI> {
+            Box::new(self.clone())
+        }
+
+        fn name(&self) -> &'static str {
+            "Always Invalid"
+        }
+    }
+
+    /// Test AI that suggests a fixed sequence of words
+    #[derive(Clone)]
+    struct ScriptedAI {
+        words: VecDeque<[char; 5]>,
+        invalid_words: Vec<[char; 5]>,
+    }
+
+    impl ScriptedAI {
+        fn new(words: Vec<[char; 5]>) -> Self {
+            Self {
+                words: words.into(),
+                invalid_words: Vec::new(),
+            }
+        }
+    }
+
+    impl WordleAI for ScriptedAI {
+        fn make_guess(&mut self) -> Option<[char; 5]> {
+            self.words.pop_front()
+        }
+
+        fn update(&mut self, _guess: [char; 5], _result: [LetterResult; 5]) {}
+
+        fn mark_invalid(&mut self, word: [char; 5]) {
+            self.invalid_words.push(word);
+        }
+
+        fn reset(&mut self) {}
+
+        fn box_clone(&self) -> Box<dyn WordleAI> {
+            Box::new(self.clone())
+        }
+
+        fn name(&self) -> &'static str {
+            "Scripted"
+        }
+    }
+}
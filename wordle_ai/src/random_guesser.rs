@@ -8,6 +8,7 @@ use wordle_core::LetterResult;
 ///
 /// This strategy randomly guesses words from the candidate list without replacement,
 /// ignoring any information given from past guesses.
+#[derive(Clone, Debug)]
 pub struct RandomGuesser {
     /// All available words
     wordlist: Vec<[char; 5]>,
@@ -74,6 +75,14 @@ impl WordleAI for RandomGuesser {
         self.available_indices = (0..self.wordlist.len()).collect();
         self.invalid_words.clear();
     }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Random Guesser"
+    }
 }
 
 #[cfg(test)]
@@ -163,4 +172,23 @@ mod tests {
         let guess2 = guesser.make_guess();
         assert!(guess2.is_some());
     }
+
+    #[test]
+    fn test_random_guesser_clone_snapshot() {
+        let wordlist = vec![
+            ['h', 'e', 'l', 'l', 'o'],
+            ['w', 'o', 'r', 'l', 'd'],
+            ['t', 'e', 's', 't', 's'],
+            ['c', 'r', 'a', 'n', 'e'],
+        ];
+        let mut guesser = RandomGuesser::with_seed(wordlist, 42);
+        guesser.make_guess();
+
+        // A clone continues exactly like the original, including its random choices
+        let mut snapshot = guesser.clone();
+        let remaining: Vec<_> = std::iter::from_fn(|| guesser.make_guess()).collect();
+        let replayed: Vec<_> = std::iter::from_fn(|| snapshot.make_guess()).collect();
+        assert_eq!(remaining.len(), 3);
+        assert_eq!(remaining, replayed);
+    }
 }
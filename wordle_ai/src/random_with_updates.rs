@@ -1,3 +1,4 @@
+use crate::candidate_tracker::CandidateTracker;
 use crate::{WordleAI, knowledge::Knowledge};
 use rand::SeedableRng;
 use rand::prelude::*;
@@ -9,13 +10,12 @@ use wordle_core::LetterResult;
 /// This strategy randomly guesses from a candidate set that is updated based on
 /// feedback from previous guesses. It uses a Knowledge base to track constraints
 /// and filter out words that don't match what we've learned.
+#[derive(Clone, Debug)]
 pub struct RandomWithUpdates {
-    /// All available words
-    wordlist: Vec<[char; 5]>,
-    /// Knowledge about the hidden word
-    knowledge: Knowledge,
-    /// Words that have been marked as invalid (not in the game's word list)
-    invalid_words: HashSet<[char; 5]>,
+    /// The feedback so far and the words that still fit it
+    tracker: CandidateTracker,
+    /// Whether to favor candidates that test letters not guessed yet
+    novelty_bias: bool,
     /// Random number generator
     rng: StdRng,
 }
@@ -24,9 +24,8 @@ impl RandomWithUpdates {
     /// Create a new RandomWithUpdates with the given word list
     pub fn new(wordlist: Vec<[char; 5]>) -> Self {
         Self {
-            wordlist,
-            knowledge: Knowledge::new(),
-            invalid_words: HashSet::new(),
+            tracker: CandidateTracker::new(wordlist),
+            novelty_bias: false,
             rng: StdRng::from_rng(&mut rand::rng()),
         }
     }
@@ -34,47 +33,119 @@ impl RandomWithUpdates {
     /// Create a new RandomWithUpdates with a specific seed (useful for testing)
     pub fn with_seed(wordlist: Vec<[char; 5]>, seed: u64) -> Self {
         Self {
-            wordlist,
-            knowledge: Knowledge::new(),
-            invalid_words: HashSet::new(),
+            tracker: CandidateTracker::new(wordlist),
+            novelty_bias: false,
             rng: StdRng::seed_from_u64(seed),
         }
     }
 
-    /// Get all candidate words that match current knowledge
-    fn get_candidates(&self) -> Vec<[char; 5]> {
-        self.wordlist
-            .iter()
-            .filter(|&&word| !self.invalid_words.contains(&word) && self.knowledge.matches(&word))
-            .copied()
-            .collect()
+    /// Always open with the given word instead of the strategy's own first guess
+    ///
+    /// Later guesses follow the normal strategy; `reset` brings the forced opener back.
+    pub fn with_forced_opener(mut self, word: [char; 5]) -> Self {
+        self.tracker.set_forced_opener(word);
+        self
+    }
+
+    /// Relax the knowledge instead of giving up when the feedback leaves no candidate
+    ///
+    /// The oldest feedback is dropped until some word matches again, see [`Knowledge::relax`].
+    pub fn with_lenient_mode(mut self) -> Self {
+        self.tracker.set_lenient();
+        self
+    }
+
+    /// Favor candidates that also test letters which haven't been guessed yet, picking them
+    /// with randomness from `seed`
+    ///
+    /// Each candidate is picked with weight 1 + the number of its distinct letters not in any
+    /// previous guess, instead of uniformly. The seed replaces the one of [`Self::with_seed`],
+    /// so the weighted picks are reproducible either way.
+    pub fn with_novelty_bias(mut self, seed: u64) -> Self {
+        self.novelty_bias = true;
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Selection weight of a candidate under the novelty bias
+    fn novelty_weight(&self, word: &[char; 5], guessed_letters: &HashSet<char>) -> usize {
+        let letters: HashSet<char> = word.iter().copied().collect();
+        1 + letters.difference(guessed_letters).count()
     }
 }
 
 impl WordleAI for RandomWithUpdates {
     fn make_guess(&mut self) -> Option<[char; 5]> {
-        let candidates = self.get_candidates();
+        if let Some(opener) = self.tracker.opener() {
+            return Some(opener);
+        }
+
+        self.tracker.relax_if_lenient();
+        let candidates = self.tracker.candidates();
 
         if candidates.is_empty() {
             return None;
         }
 
+        if self.novelty_bias {
+            let guessed_letters: HashSet<char> = self
+                .tracker
+                .guessed_words()
+                .iter()
+                .flatten()
+                .copied()
+                .collect();
+            let weights: Vec<usize> = candidates
+                .iter()
+                .map(|word| self.novelty_weight(word, &guessed_letters))
+                .collect();
+
+            // Walk the cumulative weights until the random target is passed
+            let mut target = self.rng.random_range(0..weights.iter().sum::<usize>());
+            for (word, weight) in candidates.iter().zip(weights) {
+                if target < weight {
+                    return Some(*word);
+                }
+                target -= weight;
+            }
+            unreachable!("target is below the total weight");
+        }
+
         // Pick a random candidate
         let idx = self.rng.random_range(0..candidates.len());
         Some(candidates[idx])
     }
 
     fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
-        self.knowledge.update(guess, result);
+        self.tracker.update(guess, result);
     }
 
     fn mark_invalid(&mut self, word: [char; 5]) {
-        self.invalid_words.insert(word);
+        self.tracker.mark_invalid(word);
     }
 
     fn reset(&mut self) {
-        self.knowledge = Knowledge::new();
-        self.invalid_words.clear();
+        self.tracker.reset();
+    }
+
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        Box::new(self.clone())
+    }
+
+    fn name(&self) -> &'static str {
+        "Random with Updates"
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        Some(self.tracker.knowledge())
+    }
+
+    fn remaining_candidates(&self) -> Option<&[[char; 5]]> {
+        Some(self.tracker.candidates())
+    }
+
+    fn information_progress(&self) -> f64 {
+        self.tracker.information_progress()
     }
 }
 
@@ -165,17 +236,33 @@ mod tests {
     }
 
     #[test]
-    fn test_random_with_updates_filters_candidates() {
-        let wordlist = vec![
-            ['a', 'p', 'p', 'l', 'e'],
-            ['a', 'b', 'o', 'u', 't'],
-            ['h', 'e', 'l', 'l', 'o'],
-        ];
+    fn test_random_with_updates_exposes_knowledge() {
+        let wordlist = vec![['a', 'p', 'p', 'l', 'e']];
         let mut ai = RandomWithUpdates::with_seed(wordlist, 42);
+        let result = [
+            LetterResult::Correct,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+        ];
+        ai.update(['a', 'b', 'c', 'd', 'e'], result);
+
+        let knowledge = ai.knowledge().unwrap();
+        assert_eq!(
+            knowledge.fixed_positions(),
+            &[true, false, false, false, false]
+        );
+        assert!(!knowledge.possible_letters()[1].contains(&'b'));
+        assert!(knowledge.possible_letters()[1].contains(&'z'));
+    }
 
-        // Simulate guess with first letter 'a' being correct, rest absent
-        // This means: position 0 must be 'a', and 'p', 'l', 'e' are not in the word
-        let guess = ['a', 'p', 'p', 'l', 'e'];
+    #[test]
+    fn test_random_with_updates_novelty_bias() {
+        let stale = ['a', 'a', 'a', 'a', 'a'];
+        let novel = ['a', 'f', 'g', 'h', 'i'];
+        let wordlist = vec![stale, novel];
+        let guess = ['a', 'b', 'c', 'd', 'e'];
         let result = [
             LetterResult::Correct,
             LetterResult::Absent,
@@ -183,46 +270,105 @@ mod tests {
             LetterResult::Absent,
             LetterResult::Absent,
         ];
-        ai.update(guess, result);
 
-        // Get candidates - should only include words starting with 'a' and not containing 'p', 'l', 'e'
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 1); // Only 'about' matches
-        assert!(candidates.contains(&['a', 'b', 'o', 'u', 't']));
-        assert!(!candidates.contains(&['a', 'p', 'p', 'l', 'e'])); // contains 'p', 'l', 'e'
-        assert!(!candidates.contains(&['h', 'e', 'l', 'l', 'o'])); // doesn't start with 'a'
+        let count_novel = |mut ai: RandomWithUpdates| {
+            ai.update(guess, result);
+            (0..1000).filter(|_| ai.make_guess() == Some(novel)).count()
+        };
+
+        // 'aaaaa' only tests the already guessed 'a', so it gets weight 1 against 5 for 'afghi'
+        let uniform = count_novel(RandomWithUpdates::with_seed(wordlist.clone(), 42));
+        let biased = count_novel(RandomWithUpdates::new(wordlist).with_novelty_bias(42));
+        assert!((400..600).contains(&uniform), "{}", uniform);
+        assert!((780..880).contains(&biased), "{}", biased);
     }
 
     #[test]
-    fn test_random_with_updates_mark_invalid() {
-        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['a', 'b', 'o', 'u', 't']];
-        let mut ai = RandomWithUpdates::with_seed(wordlist, 42);
+    fn test_knowledge_relax_drops_oldest_feedback() {
+        let mut knowledge = Knowledge::new();
+        knowledge.update(['a', 'b', 'c', 'd', 'e'], [LetterResult::Absent; 5]);
+        let result = [
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Misplaced,
+        ];
+        knowledge.update(['x', 'y', 'z', 'w', 'a'], result);
+
+        // 'a' was reported both absent and misplaced, so no word can match anymore
+        assert!(!knowledge.matches(&['a', 'q', 'q', 'q', 'q']));
 
-        // Mark 'apple' as invalid
-        ai.mark_invalid(['a', 'p', 'p', 'l', 'e']);
+        assert!(knowledge.relax());
+        assert!(knowledge.is_relaxed());
+        assert!(knowledge.matches(&['a', 'q', 'q', 'q', 'q']));
 
-        // Get candidates - should not include 'apple'
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 1);
-        assert!(candidates.contains(&['a', 'b', 'o', 'u', 't']));
+        // Dropping the last feedback leaves no constraints at all
+        assert!(knowledge.relax());
+        assert!(knowledge.matches(&['h', 'e', 'l', 'l', 'o']));
+        assert!(!knowledge.relax());
     }
 
     #[test]
-    fn test_random_with_updates_reset() {
-        let wordlist = vec![['a', 'p', 'p', 'l', 'e'], ['h', 'e', 'l', 'l', 'o']];
-        let mut ai = RandomWithUpdates::with_seed(wordlist.clone(), 42);
+    fn test_random_with_updates_lenient_mode() {
+        let wordlist = vec![['a', 'b', 'o', 'u', 't']];
+        // Mistaken feedback rules out the only word
+        let guess = ['a', 'p', 'p', 'l', 'e'];
+
+        let mut strict = RandomWithUpdates::with_seed(wordlist.clone(), 42);
+        strict.update(guess, [LetterResult::Absent; 5]);
+        assert_eq!(strict.make_guess(), None);
 
-        // Update knowledge
-        ai.update(['a', 'p', 'p', 'l', 'e'], [LetterResult::Correct; 5]);
+        let mut lenient = RandomWithUpdates::with_seed(wordlist, 42).with_lenient_mode();
+        lenient.update(guess, [LetterResult::Absent; 5]);
+        assert_eq!(lenient.make_guess(), Some(['a', 'b', 'o', 'u', 't']));
+        assert!(lenient.knowledge().unwrap().is_relaxed());
+    }
 
-        // Mark word as invalid
-        ai.mark_invalid(['h', 'e', 'l', 'l', 'o']);
+    #[test]
+    fn test_explain_elimination() {
+        let wordlist = vec![['a', 'b', 'o', 'u', 't']];
+        let mut ai = RandomWithUpdates::with_seed(wordlist, 42);
+        let result = [
+            LetterResult::Correct,
+            LetterResult::Misplaced,
+            LetterResult::Absent,
+            LetterResult::Misplaced,
+            LetterResult::Absent,
+        ];
+        ai.update(['s', 'e', 'z', 'l', 'y'], result);
+
+        // Still a candidate
+        assert_eq!(ai.explain_elimination(&['s', 'l', 'e', 'e', 'p']), None);
+
+        let reasons = ai.explain_elimination(&['a', 'e', 'z', 'o', 'n']).unwrap();
+        assert_eq!(
+            reasons,
+            vec![
+                "position 1 must be S",
+                "position 2 can't be E",
+                "contains absent letter Z",
+                "must contain L",
+                "must contain S",
+            ]
+        );
+    }
 
-        // Reset
-        ai.reset();
+    #[test]
+    fn test_explain_elimination_repeated_letter() {
+        let mut knowledge = Knowledge::new();
+        let result = [
+            LetterResult::Misplaced,
+            LetterResult::Misplaced,
+            LetterResult::Absent,
+            LetterResult::Absent,
+            LetterResult::Absent,
+        ];
+        knowledge.update(['e', 'e', 'x', 'y', 'z'], result);
 
-        // After reset, all words should be candidates again
-        let candidates = ai.get_candidates();
-        assert_eq!(candidates.len(), 2);
+        assert_eq!(
+            knowledge.explain_mismatch(&['a', 'b', 'e', 'c', 'd']),
+            vec!["must contain E at least 2 times"]
+        );
     }
 }
@@ -0,0 +1,169 @@
+use crate::{EntropyGuesser, WordleAI};
+use wordle_core::{Language, LetterResult, display_upper};
+
+/// Number of candidates the AI's choice is searched against while reviewing,
+/// see [`EntropyGuesser::with_sample_limit`]
+pub const REVIEW_SAMPLE_LIMIT: usize = 500;
+
+/// How a player's guess compares to the Entropy Guesser's choice at the same point
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessReview {
+    /// The word the player guessed
+    pub guess: [char; 5],
+    /// Expected information of the player's guess, in bits
+    pub bits: f64,
+    /// The word the AI would have guessed
+    pub best: [char; 5],
+    /// Expected information of the AI's guess, in bits
+    pub best_bits: f64,
+}
+
+impl GuessReview {
+    /// Bits the guess gave away compared to the AI's choice, 0 if it was at least as good
+    pub fn bits_lost(&self) -> f64 {
+        (self.best_bits - self.bits).max(0.0)
+    }
+
+    /// Whether the guess was as good as the AI's choice, up to rounding to hundredths of a bit
+    pub fn is_optimal(&self) -> bool {
+        self.guess == self.best || self.bits_lost() < 0.005
+    }
+
+    /// One line for the player on how the guess compares to the AI's choice
+    pub fn summary(&self) -> String {
+        let word =
+            |word: &[char; 5]| -> String { word.iter().map(|&c| display_upper(c)).collect() };
+
+        if self.is_optimal() {
+            format!(
+                "{}: {:.2} bits, as good as the AI's {}",
+                word(&self.guess),
+                self.bits,
+                word(&self.best)
+            )
+        } else {
+            format!(
+                "{}: {:.2} bits, optimal was {} ({:.2} bits), you lost {:.2} bits",
+                word(&self.guess),
+                self.bits,
+                word(&self.best),
+                self.best_bits,
+                self.bits_lost()
+            )
+        }
+    }
+}
+
+/// Replay a finished game and rate every guess against the Entropy Guesser's choice
+///
+/// Both words are rated by [`EntropyGuesser::evaluate_guess`] against the candidates left
+/// before the guess. The AI opens with the language's first recommended opener, since
+/// searching the best opener takes long, and searches later guesses against a sample of
+/// [`REVIEW_SAMPLE_LIMIT`] candidates. The review stops early if the feedback leaves no
+/// candidate, i.e. it contradicts itself.
+///
+/// # Arguments
+/// * `language` - The language the game was played in
+/// * `guesses` - Every guess of the game and its feedback, in order
+pub fn review_guesses(
+    language: Language,
+    guesses: &[([char; 5], [LetterResult; 5])],
+) -> Vec<GuessReview> {
+    let mut ai = EntropyGuesser::new(language.wordlist_array().to_vec())
+        .with_forced_opener(language.recommended_openers()[0])
+        .with_sample_limit(REVIEW_SAMPLE_LIMIT);
+
+    let mut reviews = Vec::new();
+    for &(guess, result) in guesses {
+        let Some(best) = ai.make_guess() else {
+            break;
+        };
+        let Some(bits) = ai.evaluate_guess(&guess) else {
+            break;
+        };
+        // Summing in another order may differ in the last bit, so don't rate the same word twice
+        let best_bits = if best == guess {
+            bits
+        } else {
+            ai.evaluate_guess(&best).unwrap_or(bits)
+        };
+
+        reviews.push(GuessReview {
+            guess,
+            bits,
+            best,
+            best_bits,
+        });
+        ai.update(guess, result);
+    }
+    reviews
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wordle_core::take_guess;
+
+    fn word(s: &str) -> [char; 5] {
+        s.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    fn played(solution: &str, guesses: &[&str]) -> Vec<([char; 5], [LetterResult; 5])> {
+        guesses
+            .iter()
+            .map(|&guess| (word(guess), take_guess(&word(solution), &word(guess))))
+            .collect()
+    }
+
+    #[test]
+    fn test_review_guesses() {
+        let guesses = played("chest", &["slate", "fuzzy", "chest"]);
+        let reviews = review_guesses(Language::English, &guesses);
+
+        assert_eq!(reviews.len(), 3);
+        // The opener is the one the AI would play, so nothing is lost
+        assert_eq!(reviews[0].best, word("slate"));
+        assert_eq!(reviews[0].bits_lost(), 0.0);
+        assert!(reviews[0].is_optimal());
+        // A word of mostly rare and repeated letters tells much less than the AI's choice
+        assert_eq!(reviews[1].guess, word("fuzzy"));
+        assert!(reviews[1].bits_lost() > 1.0);
+        assert!(!reviews[1].is_optimal());
+        assert!(reviews.iter().all(|review| review.bits >= 0.0));
+    }
+
+    #[test]
+    fn test_review_stops_at_contradiction() {
+        let mut guesses = played("chest", &["slate"]);
+        // No word fits both feedbacks for the same guess
+        guesses.push((word("slate"), [LetterResult::Correct; 5]));
+        guesses.push((word("chest"), [LetterResult::Correct; 5]));
+
+        let reviews = review_guesses(Language::English, &guesses);
+        assert_eq!(reviews.len(), 2);
+    }
+
+    #[test]
+    fn test_summary() {
+        let review = GuessReview {
+            guess: word("fuzzy"),
+            bits: 1.5,
+            best: word("slate"),
+            best_bits: 5.25,
+        };
+        assert_eq!(
+            review.summary(),
+            "FUZZY: 1.50 bits, optimal was SLATE (5.25 bits), you lost 3.75 bits"
+        );
+
+        let optimal = GuessReview {
+            guess: word("slate"),
+            bits: 5.25,
+            ..review
+        };
+        assert_eq!(
+            optimal.summary(),
+            "SLATE: 5.25 bits, as good as the AI's SLATE"
+        );
+    }
+}
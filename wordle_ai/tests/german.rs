@@ -0,0 +1,42 @@
+use wordle_ai::{HeuristicGuesser, WordleAI, play};
+use wordle_core::{Game, Language};
+
+/// Every German word with the letter, in word list order
+fn words_with(letter: char) -> Vec<[char; 5]> {
+    Language::German
+        .wordlist_array()
+        .iter()
+        .filter(|word| word.contains(&letter))
+        .copied()
+        .collect()
+}
+
+#[test]
+fn test_heuristic_guesser_solves_german_words_with_umlauts() {
+    let wordlist = Language::German.wordlist_array().to_vec();
+    let mut ai = HeuristicGuesser::new(wordlist);
+
+    let words = words_with('ü');
+    assert!(!words.is_empty());
+    for solution in words.iter().step_by(words.len() / 10 + 1) {
+        ai.reset();
+        let mut game = Game::new_with_word(6, Language::German, *solution).unwrap();
+        let outcome = play(&mut ai, &mut game);
+        assert!(outcome.solved, "failed to solve {:?}", solution);
+    }
+}
+
+#[test]
+fn test_heuristic_guesser_solves_german_word_with_sharp_s() {
+    let wordlist = Language::German.wordlist_array().to_vec();
+    let mut ai = HeuristicGuesser::new(wordlist);
+    let solution = ['a', 'u', 'ß', 'e', 'n'];
+
+    let mut game = Game::new_with_word(6, Language::German, solution).unwrap();
+    let outcome = play(&mut ai, &mut game);
+    assert!(outcome.solved);
+    assert_eq!(
+        outcome.guesses.last().map(|(guess, _)| *guess),
+        Some(solution)
+    );
+}
@@ -5,14 +5,19 @@ use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Gauge, Paragraph},
 };
-use wordle_ai::WordleAI;
-use wordle_core::LetterResult;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use wordle_ai::{Knowledge, WordleAI, group_anagrams};
+use wordle_core::{LetterResult, WORD_LENGTH, cycle_feedback, display_upper};
 
-use crate::common::{AIType, WORD_LENGTH, create_ai, get_wordlist};
+use crate::common::{AIOptions, AIType, create_ai};
 use wordle_core::Language;
 
+/// Up to this many remaining candidates are listed, most likely first
+const ENDGAME_CANDIDATES: usize = 10;
+
 enum FeedbackInputState {
     EnteringFeedback {
         current_position: usize,
@@ -23,7 +28,10 @@ enum FeedbackInputState {
 
 struct App {
     ai: Box<dyn WordleAI>,
-    ai_type: AIType,
+    /// Whether the AI ignores contradicting feedback instead of the assistant refusing it
+    lenient: bool,
+    /// Word frequencies to rank the last few candidates by
+    frequencies: Option<HashMap<[char; 5], f64>>,
     current_recommendation: Option<[char; 5]>,
     feedback_state: FeedbackInputState,
     history: Vec<([char; 5], [LetterResult; 5])>,
@@ -32,37 +40,81 @@ struct App {
 }
 
 impl App {
-    fn new(ai_type: AIType, language: Language) -> Self {
-        let wordlist = get_wordlist(language).to_vec();
-        let mut ai = create_ai(ai_type, wordlist);
-        let current_recommendation = ai.make_guess();
+    fn new(
+        ai_type: AIType,
+        language: Language,
+        history: Vec<([char; 5], [LetterResult; 5])>,
+        lenient: bool,
+        frequencies: Option<HashMap<[char; 5], f64>>,
+    ) -> Self {
+        let mut ai = new_ai(ai_type, language, lenient);
+
+        // Replay guesses that were already made in another game
+        for &(word, feedback) in &history {
+            ai.update(word, feedback);
+        }
+        let won = history
+            .last()
+            .is_some_and(|(_, feedback)| feedback.iter().all(|&f| f == LetterResult::Correct));
+        let current_recommendation = if won { None } else { ai.make_guess() };
+
+        let (error_message, info_message) = if won {
+            (
+                None,
+                Some("Congratulations! You won! Press Q to quit or R to restart.".to_string()),
+            )
+        } else if current_recommendation.is_none() {
+            (Some("AI has no more words to suggest!".to_string()), None)
+        } else if !history.is_empty() {
+            (None, Some(format!("Imported {} guesses", history.len())))
+        } else {
+            (None, None)
+        };
 
         Self {
             ai,
-            ai_type,
+            lenient,
+            frequencies,
             current_recommendation,
             feedback_state: FeedbackInputState::WaitingForNextWord,
-            history: Vec::new(),
-            error_message: None,
-            info_message: None,
+            history,
+            error_message,
+            info_message,
         }
     }
 
-    /// Cycle through feedback options
-    /// - forward: Absent -> Misplaced -> Correct -> Absent
-    /// - backward: Absent -> Correct -> Misplaced -> Absent
-    fn cycle_feedback(current: Option<LetterResult>, forward: bool) -> LetterResult {
-        match (current, forward) {
-            (None, true) => LetterResult::Misplaced,
-            (Some(LetterResult::Absent), true) => LetterResult::Misplaced,
-            (Some(LetterResult::Misplaced), true) => LetterResult::Correct,
-            (Some(LetterResult::Correct), true) => LetterResult::Absent,
-
-            (None, false) => LetterResult::Absent,
-            (Some(LetterResult::Absent), false) => LetterResult::Correct,
-            (Some(LetterResult::Misplaced), false) => LetterResult::Absent,
-            (Some(LetterResult::Correct), false) => LetterResult::Misplaced,
+    /// The remaining candidates once only a few are left, most likely first
+    ///
+    /// Each word comes with its share of the candidates' total frequency. Without
+    /// frequencies (or if none of the candidates has one), the words are sorted
+    /// alphabetically and come without a share.
+    fn endgame_candidates(&self) -> Option<Vec<([char; 5], Option<f64>)>> {
+        self.current_recommendation?;
+        let candidates = self.ai.remaining_candidates()?;
+        if candidates.is_empty() || candidates.len() > ENDGAME_CANDIDATES {
+            return None;
         }
+
+        let frequency = |word: &[char; 5]| {
+            self.frequencies
+                .as_ref()
+                .and_then(|frequencies| frequencies.get(word))
+                .copied()
+                .unwrap_or(0.0)
+        };
+        let total: f64 = candidates.iter().map(frequency).sum();
+
+        let mut words: Vec<_> = candidates
+            .iter()
+            .map(|word| (*word, (total > 0.0).then(|| frequency(word) / total)))
+            .collect();
+        words.sort_by(|(word_a, share_a), (word_b, share_b)| {
+            share_b
+                .partial_cmp(share_a)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| word_a.cmp(word_b))
+        });
+        Some(words)
     }
 
     fn handle_key(&mut self, key: KeyEvent) {
@@ -94,6 +146,12 @@ impl App {
                             self.info_message = Some("Use ←/→ to navigate, ↑/↓ or C/M/A to set feedback, Enter to submit, N to mark word as not in list".to_string());
                         }
                     }
+                    KeyCode::Char('w') | KeyCode::Char('W') => {
+                        // The recommendation was the solution, no need to enter all-green feedback
+                        if self.current_recommendation.is_some() {
+                            self.submit_feedback([Some(LetterResult::Correct); 5]);
+                        }
+                    }
                     KeyCode::Char('n') | KeyCode::Char('N') => {
                         // Mark word as not in list
                         if let Some(word) = self.current_recommendation {
@@ -132,12 +190,12 @@ impl App {
                     }
                     KeyCode::Up => {
                         feedback[*current_position] =
-                            Some(Self::cycle_feedback(feedback[*current_position], true));
+                            Some(cycle_feedback(feedback[*current_position], true));
                         self.error_message = None;
                     }
                     KeyCode::Down => {
                         feedback[*current_position] =
-                            Some(Self::cycle_feedback(feedback[*current_position], false));
+                            Some(cycle_feedback(feedback[*current_position], false));
                         self.error_message = None;
                     }
                     KeyCode::Char('c') | KeyCode::Char('C') => {
@@ -212,6 +270,21 @@ impl App {
                 return;
             }
 
+            // Refuse feedback that contradicts the earlier feedback, unless lenient mode is
+            // meant to recover from it
+            if !self.lenient
+                && let Some(reason) = self
+                    .ai
+                    .knowledge()
+                    .and_then(|knowledge| knowledge.find_contradiction(word, feedback_unwrapped))
+            {
+                self.error_message = Some(format!(
+                    "This feedback contradicts earlier feedback: {}. Please check the colors.",
+                    reason
+                ));
+                return;
+            }
+
             // Update AI with feedback
             self.ai.update(word, feedback_unwrapped);
             self.history.push((word, feedback_unwrapped));
@@ -228,13 +301,9 @@ impl App {
         }
     }
 
-    fn reset(&mut self, language: Language) {
-        let wordlist = get_wordlist(language).to_vec();
-        let mut ai = create_ai(self.ai_type, wordlist);
-        let current_recommendation = ai.make_guess();
-
-        self.ai = ai;
-        self.current_recommendation = current_recommendation;
+    fn reset(&mut self) {
+        self.ai.reset();
+        self.current_recommendation = self.ai.make_guess();
         self.feedback_state = FeedbackInputState::WaitingForNextWord;
         self.history.clear();
         self.error_message = None;
@@ -242,15 +311,37 @@ impl App {
     }
 }
 
-pub fn run_assistant(ai_type: AIType, language: Language) -> Result<()> {
+/// Create the assistant's AI, optionally in lenient mode
+fn new_ai(ai_type: AIType, language: Language, lenient: bool) -> Box<dyn WordleAI> {
+    let options = AIOptions {
+        lenient,
+        ..AIOptions::default()
+    };
+    create_ai(ai_type, language, &options)
+}
+
+pub fn run_assistant(
+    ai_type: AIType,
+    language: Language,
+    history: Vec<([char; 5], [LetterResult; 5])>,
+    lenient: bool,
+    frequencies: Option<HashMap<[char; 5], f64>>,
+) -> Result<()> {
     let terminal = ratatui::init();
-    let result = run(terminal, ai_type, language);
+    let result = run(terminal, ai_type, language, history, lenient, frequencies);
     ratatui::restore();
     result
 }
 
-fn run(mut terminal: DefaultTerminal, ai_type: AIType, language: Language) -> Result<()> {
-    let mut app = App::new(ai_type, language);
+fn run(
+    mut terminal: DefaultTerminal,
+    ai_type: AIType,
+    language: Language,
+    history: Vec<([char; 5], [LetterResult; 5])>,
+    lenient: bool,
+    frequencies: Option<HashMap<[char; 5], f64>>,
+) -> Result<()> {
+    let mut app = App::new(ai_type, language, history, lenient, frequencies);
 
     loop {
         terminal.draw(|frame| render(frame, &app))?;
@@ -260,7 +351,7 @@ fn run(mut terminal: DefaultTerminal, ai_type: AIType, language: Language) -> Re
                 break Ok(());
             }
             if key.code == KeyCode::Char('r') || key.code == KeyCode::Char('R') {
-                app.reset(language);
+                app.reset();
                 continue;
             }
             if key.code == KeyCode::Esc
@@ -277,15 +368,16 @@ fn render(frame: &mut Frame, app: &App) {
     let area = frame.area();
 
     let layout = Layout::vertical([
-        Constraint::Length(3), // Title
-        Constraint::Length(5), // Current recommendation
-        Constraint::Min(8),    // History
-        Constraint::Length(7), // Status/help
+        Constraint::Length(3),  // Title
+        Constraint::Length(5),  // Current recommendation
+        Constraint::Length(3),  // Information gathered
+        Constraint::Min(8),     // History
+        Constraint::Length(10), // Status/help
     ])
     .split(area);
 
     // Title
-    let title = Paragraph::new("WORDLE AI ASSISTANT")
+    let title = Paragraph::new(format!("WORDLE AI ASSISTANT \u{b7} {}", app.ai.name()))
         .style(Style::default().fg(Color::White).bold())
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
@@ -294,11 +386,31 @@ fn render(frame: &mut Frame, app: &App) {
     // Current recommendation
     render_recommendation(frame, app, layout[1]);
 
-    // History
-    render_history(frame, app, layout[2]);
+    // Information gathered
+    let progress = app.ai.information_progress().clamp(0.0, 1.0);
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Information Gathered"),
+        )
+        .gauge_style(Style::default().fg(Color::Green))
+        .ratio(progress);
+    frame.render_widget(gauge, layout[2]);
+
+    // History, next to the last few candidates once only a few are left
+    match app.endgame_candidates() {
+        Some(candidates) => {
+            let columns =
+                Layout::horizontal([Constraint::Min(20), Constraint::Length(24)]).split(layout[3]);
+            render_history(frame, app, columns[0]);
+            render_endgame_candidates(frame, &candidates, columns[1]);
+        }
+        None => render_history(frame, app, layout[3]),
+    }
 
     // Status and help
-    render_status(frame, app, layout[3]);
+    render_status(frame, app, layout[4]);
 }
 
 fn render_recommendation(frame: &mut Frame, app: &App, area: Rect) {
@@ -306,16 +418,7 @@ fn render_recommendation(frame: &mut Frame, app: &App, area: Rect) {
         match &app.feedback_state {
             FeedbackInputState::WaitingForNextWord => {
                 // Show word in simple format
-                let spans: Vec<Span> = word
-                    .iter()
-                    .map(|&ch| {
-                        Span::styled(
-                            format!(" {} ", ch.to_uppercase()),
-                            Style::default().fg(Color::White).bg(Color::Blue).bold(),
-                        )
-                    })
-                    .collect();
-                vec![Line::from(""), Line::from(spans)]
+                vec![Line::from(""), wordle_tui::plain_row(&word, Color::Blue)]
             }
             FeedbackInputState::EnteringFeedback {
                 current_position,
@@ -324,42 +427,12 @@ fn render_recommendation(frame: &mut Frame, app: &App, area: Rect) {
                 // Show word with feedback colors and highlight current position
                 let spans: Vec<Span> = word
                     .iter()
+                    .zip(feedback)
                     .enumerate()
-                    .map(|(i, &ch)| {
-                        let (bg_color, border_char_before, border_char_after) =
-                            if i == *current_position {
-                                // Current position - highlight
-                                match feedback[i] {
-                                    None => (Color::DarkGray, '<', '>'),
-                                    Some(LetterResult::Correct) => (Color::Green, '<', '>'),
-                                    Some(LetterResult::Misplaced) => (Color::Yellow, '<', '>'),
-                                    Some(LetterResult::Absent) => (Color::Black, '<', '>'),
-                                }
-                            } else {
-                                // Not current position
-                                match feedback[i] {
-                                    None => (Color::DarkGray, ' ', ' '),
-                                    Some(LetterResult::Correct) => (Color::Green, ' ', ' '),
-                                    Some(LetterResult::Misplaced) => (Color::Yellow, ' ', ' '),
-                                    Some(LetterResult::Absent) => (Color::Black, ' ', ' '),
-                                }
-                            };
-                        Span::styled(
-                            format!(
-                                "{}{}{}",
-                                border_char_before,
-                                ch.to_uppercase(),
-                                border_char_after
-                            ),
-                            Style::default()
-                                .fg(if bg_color == Color::Black {
-                                    Color::White
-                                } else {
-                                    Color::Black
-                                })
-                                .bg(bg_color)
-                                .bold(),
-                        )
+                    .map(|(i, (&ch, &result))| {
+                        let background =
+                            result.map_or(wordle_tui::EMPTY_COLOR, wordle_tui::result_color);
+                        wordle_tui::tile(ch, background, i == *current_position)
                     })
                     .collect();
                 vec![Line::from(""), Line::from(spans)]
@@ -387,22 +460,7 @@ fn render_history(frame: &mut Frame, app: &App, area: Rect) {
     // Show last few guesses
     let start_idx = app.history.len().saturating_sub(6);
     for (guess, results) in app.history.iter().skip(start_idx) {
-        let spans: Vec<Span> = guess
-            .iter()
-            .zip(results.iter())
-            .map(|(&ch, &result)| {
-                let color = match result {
-                    LetterResult::Correct => Color::Green,
-                    LetterResult::Misplaced => Color::Yellow,
-                    LetterResult::Absent => Color::DarkGray,
-                };
-                Span::styled(
-                    format!(" {} ", ch.to_uppercase()),
-                    Style::default().fg(Color::Black).bg(color).bold(),
-                )
-            })
-            .collect();
-        lines.push(Line::from(spans));
+        lines.push(wordle_tui::word_row(guess, results));
     }
 
     if lines.is_empty() {
@@ -418,6 +476,37 @@ fn render_history(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(history, area);
 }
 
+/// List the last few candidates, highlighting the most likely one if frequencies are known
+fn render_endgame_candidates(
+    frame: &mut Frame,
+    candidates: &[([char; 5], Option<f64>)],
+    area: Rect,
+) {
+    let lines: Vec<Line> = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, (word, share))| {
+            let word: String = word.iter().map(|&c| display_upper(c)).collect();
+            match share {
+                Some(share) if i == 0 => Line::from(Span::styled(
+                    format!("{}  {:>5.1}%", word, share * 100.0),
+                    Style::default().fg(Color::Green).bold(),
+                )),
+                Some(share) => Line::from(format!("{}  {:>5.1}%", word, share * 100.0)),
+                None => Line::from(word),
+            }
+        })
+        .collect();
+
+    let list = Paragraph::new(lines).alignment(Alignment::Center).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Remaining ({})", candidates.len())),
+    );
+
+    frame.render_widget(list, area);
+}
+
 fn render_status(frame: &mut Frame, app: &App, area: Rect) {
     let mut lines = Vec::new();
 
@@ -434,13 +523,49 @@ fn render_status(frame: &mut Frame, app: &App, area: Rect) {
         )));
     }
 
+    // Warn when lenient mode had to ignore some feedback
+    if app.ai.knowledge().is_some_and(Knowledge::is_relaxed) {
+        lines.push(Line::from(Span::styled(
+            "Warning: feedback seems inconsistent, the oldest guesses are being ignored",
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    // Warn about candidates that only a guess testing positions tells apart
+    if let Some(candidates) = app.endgame_candidates() {
+        let words: Vec<[char; 5]> = candidates.iter().map(|&(word, _)| word).collect();
+        for group in group_anagrams(&words) {
+            let group: Vec<String> = group
+                .iter()
+                .map(|word| word.iter().map(|&c| display_upper(c)).collect())
+                .collect();
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} are anagrams: you'll need a positional test",
+                    group.join(", ")
+                ),
+                Style::default().fg(Color::Yellow),
+            )));
+        }
+    }
+
+    // Show what is known about each position
+    if let Some(knowledge) = app.ai.knowledge() {
+        lines.push(Line::from(format!(
+            "Positions: {}",
+            describe_positions(knowledge)
+        )));
+    }
+
     // Show instructions based on state
     match &app.feedback_state {
         FeedbackInputState::WaitingForNextWord => {
             if app.current_recommendation.is_some() {
                 lines.push(Line::from(""));
                 lines.push(Line::from("Press Enter to enter feedback for this word"));
-                lines.push(Line::from("Press 'N' to mark word as not in list"));
+                lines.push(Line::from(
+                    "Press 'N' to mark word as not in list, 'W' if it was the solution",
+                ));
             }
             lines.push(Line::from("Press 'R' to restart, 'Q' or Esc to quit"));
         }
@@ -461,3 +586,50 @@ fn render_status(frame: &mut Frame, app: &App, area: Rect) {
 
     frame.render_widget(status, area);
 }
+
+/// Describe the possible letters for each position, e.g. `A | ? | {E,L} | not {S} | ?`
+///
+/// Fixed positions show their letter, untouched positions show `?`, and narrowed
+/// positions list whichever is shorter: the remaining or the excluded letters.
+fn describe_positions(knowledge: &Knowledge) -> String {
+    let possible_letters = knowledge.possible_letters();
+    let fixed_positions = knowledge.fixed_positions();
+
+    (0..WORD_LENGTH)
+        .map(|position| {
+            let possible = &possible_letters[position];
+            if fixed_positions[position]
+                && let Some(letter) = possible.iter().next()
+            {
+                return display_upper(*letter).to_string();
+            }
+
+            let mut remaining: Vec<char> = possible.iter().copied().collect();
+            remaining.sort_unstable();
+            let mut excluded: Vec<char> = knowledge
+                .alphabet()
+                .iter()
+                .filter(|c| !possible.contains(c))
+                .copied()
+                .collect();
+            excluded.sort_unstable();
+
+            if excluded.is_empty() {
+                "?".to_string()
+            } else if remaining.len() <= excluded.len() {
+                format!("{{{}}}", join_uppercase(&remaining))
+            } else {
+                format!("not {{{}}}", join_uppercase(&excluded))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn join_uppercase(letters: &[char]) -> String {
+    letters
+        .iter()
+        .map(|&c| display_upper(c).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
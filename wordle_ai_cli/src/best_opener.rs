@@ -0,0 +1,139 @@
+use color_eyre::eyre::{Result, bail};
+use rayon::prelude::*;
+use std::sync::{Arc, Mutex};
+use wordle_core::{DEFAULT_MAX_ATTEMPTS, Game, Language};
+
+use crate::common::{AIOptions, AIType, create_ai, get_wordlist, parse_word};
+use crate::simulate::{AgentStats, LOSS_PENALTY};
+
+/// Results of playing every answer with one opener
+struct OpenerStats {
+    opener: [char; 5],
+    stats: AgentStats,
+}
+
+impl OpenerStats {
+    /// Average guesses over all games, scored like the simulation ranks agents
+    fn avg_guesses(&self) -> f64 {
+        self.stats.composite_score(LOSS_PENALTY)
+    }
+}
+
+/// Play an AI with a forced opener against every answer of the language
+///
+/// The random agents start from the same `seed` for every opener, so the results are
+/// reproducible and only the opener differs between them.
+fn evaluate_opener(
+    ai_type: AIType,
+    language: Language,
+    opener: [char; 5],
+    seed: u64,
+) -> OpenerStats {
+    let wordlist = get_wordlist(language);
+    let options = AIOptions {
+        seed: Some(seed),
+        opener: Some(opener),
+        ..AIOptions::default()
+    };
+    let mut ai = create_ai(ai_type, language, &options);
+    let mut stats = OpenerStats {
+        opener,
+        stats: AgentStats::new(ai_type),
+    };
+
+    for &answer in wordlist {
+        ai.reset();
+        let mut game = Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, answer)
+            .expect("answers are taken from the word list");
+        let outcome = wordle_ai::play(ai.as_mut(), &mut game);
+
+        if outcome.solved {
+            stats.stats.record_win(outcome.guesses.len());
+        } else {
+            stats.stats.record_loss();
+        }
+    }
+
+    stats
+}
+
+/// Rank opening words by the average number of guesses the AI needs with them (parallelized)
+///
+/// Every word of the language is tried as opener unless `openers` restricts the candidates.
+/// Without a `seed`, the random agents get a random one, which is printed to rerun with.
+pub fn run_best_opener(
+    ai_type: AIType,
+    language: Language,
+    top: usize,
+    openers: Vec<String>,
+    seed: Option<u64>,
+) -> Result<()> {
+    if !ai_type.supports_forced_opener() {
+        bail!("{} doesn't support a forced opener", ai_type.name());
+    }
+
+    let openers: Vec<[char; 5]> = if openers.is_empty() {
+        get_wordlist(language).to_vec()
+    } else {
+        openers
+            .iter()
+            .map(|word| parse_word(word, language))
+            .collect::<Result<_>>()?
+    };
+    if let Some(opener) = openers.iter().find(|opener| !language.contains(opener)) {
+        bail!(
+            "'{}' is not in the {:?} word list",
+            opener.iter().collect::<String>(),
+            language
+        );
+    }
+    let num_answers = get_wordlist(language).len();
+    let seed = seed.unwrap_or_else(rand::random);
+
+    println!(
+        "Trying {} openers against {} answers with {}, seed {}...",
+        openers.len(),
+        num_answers,
+        ai_type.name(),
+        seed
+    );
+
+    // Progress counter
+    let progress = Arc::new(Mutex::new(0usize));
+    let report_every = (openers.len() / 100).max(1);
+
+    let mut results: Vec<OpenerStats> = openers
+        .par_iter()
+        .map(|&opener| {
+            let stats = evaluate_opener(ai_type, language, opener, seed);
+
+            let mut p = progress.lock().unwrap();
+            *p += 1;
+            if (*p).is_multiple_of(report_every) {
+                println!("Progress: {}/{}", *p, openers.len());
+            }
+
+            stats
+        })
+        .collect();
+
+    results.sort_by(|a, b| a.avg_guesses().partial_cmp(&b.avg_guesses()).unwrap());
+
+    println!();
+    println!(
+        "Top {} openers for {}:",
+        top.min(results.len()),
+        ai_type.name()
+    );
+    for (rank, stats) in results.iter().take(top).enumerate() {
+        println!(
+            "{:>3}. {}  avg {:.3} guesses, {:.1}% solved",
+            rank + 1,
+            stats.opener.iter().collect::<String>(),
+            stats.avg_guesses(),
+            stats.stats.win_rate()
+        );
+    }
+
+    Ok(())
+}
@@ -1,8 +1,16 @@
 use clap::ValueEnum;
-use wordle_ai::{EntropyGuesser, HeuristicGuesser, RandomGuesser, RandomWithUpdates, WordleAI};
-use wordle_core::Language;
+use color_eyre::eyre::{Result, eyre};
+use rand::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
+use wordle_ai::{
+    EliminationGuesser, EntropyGuesser, HeuristicGuesser, HumanLikeGuesser, RandomGuesser,
+    RandomWithUpdates, WordleAI,
+};
+use wordle_core::{GameError, Language, LetterResult, lowercase_word, normalize_guess, split_mix};
 
-pub const WORD_LENGTH: usize = 5;
+/// Number of candidates the sampled Entropy Guesser evaluates entropy against
+pub const ENTROPY_SAMPLE_LIMIT: usize = 500;
 
 #[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Hash)]
 pub enum AIType {
@@ -10,10 +18,24 @@ pub enum AIType {
     Random,
     /// AI #2: Random Guesser with Updates - uses feedback to filter candidate words
     RandomUpdates,
+    /// AI #2 variant: Random Guesser with Updates that favors candidates testing new letters
+    RandomNovelty,
     /// AI #3: Heuristic Guesser - scores words based on letter frequency
     Heuristic,
+    /// AI #3 variant: Heuristic Guesser that also scores repeated letters in the endgame
+    HeuristicDoubles,
+    /// AI #3 variant: Heuristic Guesser that only guesses five distinct letters until the endgame
+    HeuristicDistinct,
     /// AI #4: Entropy Guesser - maximizes expected information gain
     Entropy,
+    /// AI #4 variant: Entropy Guesser estimating entropy on a sample of the candidates (faster)
+    EntropySampled,
+    /// AI #4 variant: Entropy Guesser that only guesses five distinct letters until the endgame
+    EntropyDistinct,
+    /// AI #5: Elimination Guesser - minimizes expected remaining candidates
+    Elimination,
+    /// AI #6: Human-like Guesser - opens with a common word, then guesses the most common candidate
+    HumanLike,
 }
 
 impl AIType {
@@ -22,23 +44,341 @@ impl AIType {
         match self {
             AIType::Random => "Random Guesser",
             AIType::RandomUpdates => "Random with Updates",
+            AIType::RandomNovelty => "Random (novelty bias)",
             AIType::Heuristic => "Heuristic Guesser",
+            AIType::HeuristicDoubles => "Heuristic (late doubles)",
+            AIType::HeuristicDistinct => "Heuristic (distinct letters)",
             AIType::Entropy => "Entropy Guesser",
+            AIType::EntropySampled => "Entropy (sampled)",
+            AIType::EntropyDistinct => "Entropy (distinct letters)",
+            AIType::Elimination => "Elimination Guesser",
+            AIType::HumanLike => "Human-like Guesser",
         }
     }
+
+    /// Whether the AI can be forced to open with a given word
+    ///
+    /// Only the Random Guesser can't, as it ignores feedback altogether.
+    pub fn supports_forced_opener(&self) -> bool {
+        *self != AIType::Random
+    }
+}
+
+/// How to set up an AI beyond its [`AIType`]
+///
+/// The default is a strict AI with its own opener and fresh randomness.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AIOptions<'a> {
+    /// Seed for the random agents, making their guesses reproducible
+    pub seed: Option<u64>,
+    /// Word to always open with instead of the strategy's own first guess
+    pub opener: Option<[char; 5]>,
+    /// Drop the oldest feedback instead of giving up when the feedback leaves no candidate
+    pub lenient: bool,
+    /// Word frequencies for the Entropy Guesser to break ties with and the Human-like
+    /// Guesser to rank words by
+    pub frequencies: Option<&'a HashMap<[char; 5], f64>>,
+}
+
+/// The builder methods every agent that tracks feedback has, so [`create_ai`] can apply
+/// the [`AIOptions`] they share to any of them
+trait TrackingAgent: WordleAI + Sized + 'static {
+    fn forced_opener(self, word: [char; 5]) -> Self;
+
+    fn lenient_mode(self) -> Self;
+
+    /// Apply the opener and lenient mode of `options`
+    fn configure(mut self, options: &AIOptions) -> Box<dyn WordleAI> {
+        if let Some(opener) = options.opener {
+            self = self.forced_opener(opener);
+        }
+        if options.lenient {
+            self = self.lenient_mode();
+        }
+        Box::new(self)
+    }
+}
+
+impl TrackingAgent for RandomWithUpdates {
+    fn forced_opener(self, word: [char; 5]) -> Self {
+        self.with_forced_opener(word)
+    }
+
+    fn lenient_mode(self) -> Self {
+        self.with_lenient_mode()
+    }
+}
+
+impl TrackingAgent for HeuristicGuesser {
+    fn forced_opener(self, word: [char; 5]) -> Self {
+        self.with_forced_opener(word)
+    }
+
+    fn lenient_mode(self) -> Self {
+        self.with_lenient_mode()
+    }
+}
+
+impl TrackingAgent for EntropyGuesser {
+    fn forced_opener(self, word: [char; 5]) -> Self {
+        self.with_forced_opener(word)
+    }
+
+    fn lenient_mode(self) -> Self {
+        self.with_lenient_mode()
+    }
+}
+
+impl TrackingAgent for EliminationGuesser {
+    fn forced_opener(self, word: [char; 5]) -> Self {
+        self.with_forced_opener(word)
+    }
+
+    fn lenient_mode(self) -> Self {
+        self.with_lenient_mode()
+    }
+}
+
+impl TrackingAgent for HumanLikeGuesser {
+    fn forced_opener(self, word: [char; 5]) -> Self {
+        self.with_forced_opener(word)
+    }
+
+    fn lenient_mode(self) -> Self {
+        self.with_lenient_mode()
+    }
 }
 
-/// Factory function to create the appropriate AI based on type
-pub fn create_ai(ai_type: AIType, wordlist: Vec<[char; 5]>) -> Box<dyn WordleAI> {
+/// Factory function to create the appropriate AI based on type, playing with the
+/// language's word list
+///
+/// The Random Guesser ignores feedback, so it has neither a forced opener nor a lenient
+/// mode, see [`AIType::supports_forced_opener`]. The Human-like Guesser opens with the
+/// language's first recommended opener unless it has frequencies or an opener is given.
+pub fn create_ai(ai_type: AIType, language: Language, options: &AIOptions) -> Box<dyn WordleAI> {
+    let wordlist = get_wordlist(language).to_vec();
+    let random_with_updates = |wordlist: Vec<[char; 5]>| match options.seed {
+        Some(seed) => RandomWithUpdates::with_seed(wordlist, seed),
+        None => RandomWithUpdates::new(wordlist),
+    };
+    let entropy = |wordlist: Vec<[char; 5]>| match options.frequencies {
+        Some(frequencies) => EntropyGuesser::with_frequencies(wordlist, frequencies.clone()),
+        None => EntropyGuesser::new(wordlist),
+    };
+
     match ai_type {
-        AIType::Random => Box::new(RandomGuesser::new(wordlist)),
-        AIType::RandomUpdates => Box::new(RandomWithUpdates::new(wordlist)),
-        AIType::Heuristic => Box::new(HeuristicGuesser::new(wordlist)),
-        AIType::Entropy => Box::new(EntropyGuesser::new(wordlist)),
+        AIType::Random => match options.seed {
+            Some(seed) => Box::new(RandomGuesser::with_seed(wordlist, seed)),
+            None => Box::new(RandomGuesser::new(wordlist)),
+        },
+        AIType::RandomUpdates => random_with_updates(wordlist).configure(options),
+        AIType::RandomNovelty => RandomWithUpdates::new(wordlist)
+            .with_novelty_bias(options.seed.unwrap_or_else(rand::random))
+            .configure(options),
+        AIType::Heuristic => HeuristicGuesser::new(wordlist).configure(options),
+        AIType::HeuristicDoubles => HeuristicGuesser::new(wordlist)
+            .with_late_double_letter_bias()
+            .configure(options),
+        AIType::HeuristicDistinct => HeuristicGuesser::new(wordlist)
+            .with_distinct_letters_only()
+            .configure(options),
+        AIType::Entropy => entropy(wordlist).configure(options),
+        AIType::EntropySampled => entropy(wordlist)
+            .with_sample_limit(ENTROPY_SAMPLE_LIMIT)
+            .configure(options),
+        AIType::EntropyDistinct => entropy(wordlist)
+            .with_distinct_letters_only()
+            .configure(options),
+        AIType::Elimination => EliminationGuesser::new(wordlist).configure(options),
+        AIType::HumanLike => match options.frequencies {
+            Some(frequencies) => {
+                HumanLikeGuesser::with_frequencies(wordlist, frequencies.clone()).configure(options)
+            }
+            // Without frequencies, it would open with the alphabetically first word, so
+            // default to a recommended opener like a person would
+            None => {
+                let opener = options
+                    .opener
+                    .or(language.recommended_openers().first().copied());
+                HumanLikeGuesser::new(wordlist).configure(&AIOptions { opener, ..*options })
+            }
+        },
     }
 }
 
+/// Load word frequencies from a file with one "word count" pair per line
+///
+/// Words that are not exactly 5 letters long are skipped.
+pub fn load_frequencies(path: &Path) -> Result<HashMap<[char; 5], f64>> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut frequencies = HashMap::new();
+
+    for (line_number, line) in contents.lines().enumerate() {
+        let mut parts = line.split_whitespace();
+        let (Some(word), Some(count)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let count: f64 = count
+            .parse()
+            .map_err(|_| eyre!("Invalid count on line {}: {}", line_number + 1, line))?;
+        let chars = lowercase_word(word);
+        if let Ok(word) = <[char; 5]>::try_from(chars) {
+            frequencies.insert(word, count);
+        }
+    }
+
+    Ok(frequencies)
+}
+
+/// Parse a 5-letter word typed by the user, see [`normalize_guess`]
+pub fn parse_word(word: &str, language: Language) -> Result<[char; 5]> {
+    normalize_guess(word, language).map_err(|err| match err {
+        GameError::InvalidChar(c) => eyre!("'{}' contains '{}', which is not a letter", word, c),
+        _ => eyre!("'{}' is not a 5-letter word", word),
+    })
+}
+
+/// Parse feedback like `GYBBG` typed by the user, see [`wordle_core::parse_feedback`]
+pub fn parse_feedback(feedback: &str) -> Result<[LetterResult; 5]> {
+    wordle_core::parse_feedback(feedback).map_err(|err| match err {
+        GameError::InvalidChar(c) => eyre!("Invalid feedback letter '{}' in '{}'", c, feedback),
+        _ => eyre!("Feedback '{}' must have exactly 5 letters", feedback),
+    })
+}
+
+/// Parse a guess history like `slate=BBGYB;crane=BGBBB`
+pub fn parse_history(
+    history: &str,
+    language: Language,
+) -> Result<Vec<([char; 5], [LetterResult; 5])>> {
+    history
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (word, feedback) = entry
+                .split_once('=')
+                .ok_or_else(|| eyre!("Expected 'word=feedback', got '{}'", entry))?;
+            Ok((
+                parse_word(word, language)?,
+                parse_feedback(feedback.trim())?,
+            ))
+        })
+        .collect()
+}
+
+/// Pick the solution of the `game_index`-th game of a seeded run, along with a seed for its
+/// random agents
+///
+/// Both are derived from the seed and the game index only, so every game is reproducible
+/// regardless of the order in which games are played. The seed is scrambled before the index
+/// is mixed in, so runs with adjacent seeds don't share games.
+pub fn seeded_solution(language: Language, seed: u64, game_index: usize) -> ([char; 5], u64) {
+    let mut rng = StdRng::seed_from_u64(split_mix(split_mix(seed) ^ game_index as u64));
+    let solution = *get_wordlist(language).choose(&mut rng).unwrap();
+    (solution, rng.random())
+}
+
 /// Get the wordlist array for a given language
 pub fn get_wordlist(language: Language) -> &'static [[char; 5]] {
     language.wordlist_array()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LetterResult::{Absent, Correct, Misplaced};
+
+    fn word(word: &str) -> [char; 5] {
+        lowercase_word(word).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        let expected = [Correct, Misplaced, Absent, Absent, Correct];
+        assert_eq!(parse_feedback("GYBBG").unwrap(), expected);
+        assert_eq!(parse_feedback("gybbg").unwrap(), expected);
+        // The assistant's key letters, in any case
+        assert_eq!(parse_feedback("CMAAC").unwrap(), expected);
+        assert_eq!(parse_feedback("cMaAg").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_feedback_invalid() {
+        for feedback in ["GYBB", "GYBBGY", "", "GYXBG", "GY BG"] {
+            assert!(
+                parse_feedback(feedback).is_err(),
+                "{:?} should be rejected",
+                feedback
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let history = parse_history("slate=BBGYB;crane=BGBBB", Language::English).unwrap();
+        assert_eq!(
+            history,
+            vec![
+                (word("slate"), [Absent, Absent, Correct, Misplaced, Absent]),
+                (word("crane"), [Absent, Correct, Absent, Absent, Absent]),
+            ]
+        );
+
+        // A trailing separator, spaces and uppercase words are fine
+        assert_eq!(
+            parse_history(" SLATE = bbgyb ; crane=BGBBB; ", Language::English).unwrap(),
+            history
+        );
+        assert!(parse_history("", Language::English).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_history_umlauts() {
+        let history = parse_history("Bäume=GYBBG;straß=BBBBB", Language::German).unwrap();
+        assert_eq!(history[0].0, word("bäume"));
+        assert_eq!(history[1].0, word("straß"));
+
+        // Umlauts aren't letters of English words
+        assert!(parse_history("bäume=GYBBG", Language::English).is_err());
+    }
+
+    #[test]
+    fn test_parse_history_invalid() {
+        for history in [
+            "slate",
+            "slate=BBGYB;crane",
+            "slate:BBGYB",
+            "slat=BBGY",
+            "slat=BBGYB",
+            "slates=BBGYBB",
+            "slate=BBGY",
+            "slate=BBGYBB",
+            "slate=BBXYB",
+        ] {
+            assert!(
+                parse_history(history, Language::English).is_err(),
+                "{:?} should be rejected",
+                history
+            );
+        }
+    }
+
+    #[test]
+    fn test_seeded_solution() {
+        // The same game of the same run is the same
+        assert_eq!(
+            seeded_solution(Language::English, 1, 5),
+            seeded_solution(Language::English, 1, 5)
+        );
+
+        // Runs with adjacent seeds don't share games shifted by one
+        let shifted = (0..20)
+            .filter(|&i| {
+                seeded_solution(Language::English, 1, i + 1).0
+                    == seeded_solution(Language::English, 2, i).0
+            })
+            .count();
+        assert_eq!(shifted, 0);
+    }
+}
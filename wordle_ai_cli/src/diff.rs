@@ -0,0 +1,111 @@
+use color_eyre::eyre::Result;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use wordle_core::{DEFAULT_MAX_ATTEMPTS, Game, Language};
+
+use crate::common::{AIOptions, AIType, create_ai, seeded_solution};
+
+/// Both agents' results on one game, as number of guesses or `None` if lost
+struct GameDiff {
+    solution: [char; 5],
+    baseline: Option<usize>,
+    candidate: Option<usize>,
+}
+
+impl GameDiff {
+    /// How the candidate did compared to the baseline, `Less` meaning better
+    fn compare(&self) -> Ordering {
+        // A loss ranks behind any win
+        let key = |guesses: Option<usize>| guesses.unwrap_or(usize::MAX);
+        key(self.candidate).cmp(&key(self.baseline))
+    }
+}
+
+/// Play one seeded game with the given agent, returning the number of guesses if solved
+fn play_seeded(
+    ai_type: AIType,
+    language: Language,
+    seed: u64,
+    game_index: usize,
+) -> ([char; 5], Option<usize>) {
+    let (solution, ai_seed) = seeded_solution(language, seed, game_index);
+    let mut game = Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, solution).unwrap();
+    let options = AIOptions {
+        seed: Some(ai_seed),
+        ..AIOptions::default()
+    };
+    let mut ai = create_ai(ai_type, language, &options);
+    let outcome = wordle_ai::play(ai.as_mut(), &mut game);
+    (solution, outcome.solved.then_some(outcome.guesses.len()))
+}
+
+fn format_result(guesses: Option<usize>) -> String {
+    guesses.map_or_else(|| "lost".to_string(), |n| n.to_string())
+}
+
+/// Run two agents on the same seeded games and report the games where they differ (parallelized)
+///
+/// Without a seed, a random one is picked and printed so the run can be repeated.
+pub fn run_diff(
+    baseline: AIType,
+    candidate: AIType,
+    language: Language,
+    num_games: usize,
+    seed: Option<u64>,
+) -> Result<()> {
+    let seed = seed.unwrap_or_else(rand::random);
+    println!(
+        "Comparing {} (baseline) with {} (candidate) on {} games, seed {}",
+        baseline.name(),
+        candidate.name(),
+        num_games,
+        seed
+    );
+
+    let mut diffs: Vec<GameDiff> = (0..num_games)
+        .into_par_iter()
+        .map(|game_index| {
+            let (solution, baseline) = play_seeded(baseline, language, seed, game_index);
+            let (_, candidate) = play_seeded(candidate, language, seed, game_index);
+            GameDiff {
+                solution,
+                baseline,
+                candidate,
+            }
+        })
+        .filter(|diff| diff.baseline != diff.candidate)
+        .collect();
+    diffs.sort_by_key(|diff| diff.solution);
+
+    println!();
+    println!("{:<8} {:>9} {:>10}", "Word", "Baseline", "Candidate");
+    for diff in &diffs {
+        let marker = match diff.compare() {
+            Ordering::Less => "better",
+            Ordering::Greater => "worse",
+            Ordering::Equal => "",
+        };
+        println!(
+            "{:<8} {:>9} {:>10}  {}",
+            diff.solution.iter().collect::<String>(),
+            format_result(diff.baseline),
+            format_result(diff.candidate),
+            marker
+        );
+    }
+
+    let better = diffs
+        .iter()
+        .filter(|diff| diff.compare() == Ordering::Less)
+        .count();
+    println!();
+    println!(
+        "{} of {} games differ: candidate better in {}, worse in {}",
+        diffs.len(),
+        num_games,
+        better,
+        diffs.len() - better
+    );
+
+    Ok(())
+}
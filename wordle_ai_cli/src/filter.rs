@@ -0,0 +1,216 @@
+use color_eyre::eyre::{Result, bail, eyre};
+use std::collections::HashMap;
+use wordle_ai::Knowledge;
+use wordle_core::{Language, WORD_LENGTH, display_upper, lowercase_word};
+
+use crate::common::get_wordlist;
+
+/// Parse a constraint pattern into a `Knowledge`
+///
+/// The pattern is a list of whitespace-separated terms:
+/// - `a..e.` - known letters at their positions, `.` for unknown (exactly 5 characters)
+/// - `+ei` - letters that must appear somewhere, as often as they are repeated (`+ee` for two e)
+/// - `-rst` - letters that don't appear at all, except where a mask fixes them
+/// - `?e2` - letter present, but not at the given position (1-5), like a yellow tile
+pub fn parse_pattern(language: Language, pattern: &str) -> Result<Knowledge> {
+    let mut knowledge = Knowledge::for_wordlist(get_wordlist(language));
+    let mut absent = Vec::new();
+    // How often each letter is required outside of masks, and the letters masks fix
+    let mut required: HashMap<char, usize> = HashMap::new();
+    let mut fixed = [None; WORD_LENGTH];
+
+    for term in pattern.split_whitespace() {
+        let chars = lowercase_word(term);
+
+        match chars.first() {
+            Some('+') => {
+                let mut counts: HashMap<char, usize> = HashMap::new();
+                for &letter in &chars[1..] {
+                    *counts.entry(letter).or_insert(0) += 1;
+                }
+                for (letter, count) in counts {
+                    knowledge.require_times(letter, count as u8);
+                    let required = required.entry(letter).or_insert(0);
+                    *required = (*required).max(count);
+                }
+            }
+            // Applied last, so that letters fixed by other terms are kept
+            Some('-') => absent.extend_from_slice(&chars[1..]),
+            Some('?') => {
+                let (&letter, position) = chars[1..]
+                    .split_first()
+                    .ok_or_else(|| eyre!("Expected '?<letter><position>', got '{}'", term))?;
+                let position: usize = position
+                    .iter()
+                    .collect::<String>()
+                    .parse()
+                    .ok()
+                    .filter(|position| (1..=WORD_LENGTH).contains(position))
+                    .ok_or_else(|| eyre!("Invalid position in '{}', expected 1-5", term))?;
+                knowledge.require(letter);
+                knowledge.exclude_at(position - 1, letter);
+                let required = required.entry(letter).or_insert(0);
+                *required = (*required).max(1);
+            }
+            _ if chars.len() == WORD_LENGTH => {
+                for (position, &letter) in chars.iter().enumerate() {
+                    if letter != '.' {
+                        knowledge.require_at(position, letter);
+                        fixed[position] = Some(letter);
+                    }
+                }
+            }
+            _ => bail!(
+                "Invalid term '{}': expected a 5-letter mask like 'a..e.', '+letters', '-letters' or '?<letter><position>'",
+                term
+            ),
+        }
+    }
+
+    for letter in absent {
+        let fixed = fixed.iter().filter(|&&c| c == Some(letter)).count();
+        if required
+            .get(&letter)
+            .is_some_and(|&required| required > fixed)
+        {
+            bail!(
+                "Letter '{}' is both required and excluded",
+                display_upper(letter)
+            );
+        }
+        knowledge.exclude(letter);
+    }
+
+    Ok(knowledge)
+}
+
+/// Print all words of the language's word list that match the pattern, sorted
+pub fn run_filter(language: Language, pattern: &str) -> Result<()> {
+    let knowledge = parse_pattern(language, pattern)?;
+
+    let mut words: Vec<String> = get_wordlist(language)
+        .iter()
+        .filter(|word| knowledge.matches(word))
+        .map(|word| word.iter().collect())
+        .collect();
+    words.sort();
+
+    for word in &words {
+        println!("{}", word);
+    }
+    eprintln!("{} matching words", words.len());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(knowledge: &Knowledge, word: &str) -> bool {
+        knowledge.matches(&lowercase_word(word).try_into().unwrap())
+    }
+
+    #[test]
+    fn test_parse_pattern_mask() {
+        let knowledge = parse_pattern(Language::English, "a..e.").unwrap();
+        assert!(matches(&knowledge, "angel"));
+        assert!(matches(&knowledge, "amber"));
+        assert!(!matches(&knowledge, "bagel"));
+        assert!(!matches(&knowledge, "alive"));
+
+        // Letters are case-insensitive
+        let knowledge = parse_pattern(Language::English, "A..E.").unwrap();
+        assert!(matches(&knowledge, "angel"));
+    }
+
+    #[test]
+    fn test_parse_pattern_required() {
+        let knowledge = parse_pattern(Language::English, "+ei").unwrap();
+        assert!(matches(&knowledge, "field"));
+        assert!(matches(&knowledge, "alien"));
+        assert!(!matches(&knowledge, "bagel"));
+        assert!(!matches(&knowledge, "crane"));
+    }
+
+    #[test]
+    fn test_parse_pattern_required_repeated_letters() {
+        let knowledge = parse_pattern(Language::English, "+ee").unwrap();
+        assert!(matches(&knowledge, "geese"));
+        assert!(!matches(&knowledge, "bagel"));
+
+        // Repeating a letter across terms doesn't add up
+        let knowledge = parse_pattern(Language::English, "+e +e").unwrap();
+        assert!(matches(&knowledge, "bagel"));
+    }
+
+    #[test]
+    fn test_parse_pattern_absent() {
+        let knowledge = parse_pattern(Language::English, "-rst").unwrap();
+        assert!(matches(&knowledge, "angel"));
+        assert!(!matches(&knowledge, "crane"));
+        assert!(!matches(&knowledge, "slate"));
+
+        // A letter fixed by a mask is only excluded elsewhere
+        let knowledge = parse_pattern(Language::English, "-e .e...").unwrap();
+        assert!(matches(&knowledge, "beach"));
+        assert!(!matches(&knowledge, "geese"));
+    }
+
+    #[test]
+    fn test_parse_pattern_misplaced() {
+        let knowledge = parse_pattern(Language::English, "?e2").unwrap();
+        assert!(matches(&knowledge, "angel"));
+        assert!(!matches(&knowledge, "beach"));
+        assert!(!matches(&knowledge, "crank"));
+    }
+
+    #[test]
+    fn test_parse_pattern_combined() {
+        let knowledge = parse_pattern(Language::English, "a.... +l ?e5 -rst").unwrap();
+        assert!(matches(&knowledge, "angel"));
+        assert!(!matches(&knowledge, "apple"));
+        assert!(!matches(&knowledge, "alert"));
+    }
+
+    #[test]
+    fn test_parse_pattern_umlauts() {
+        let knowledge = parse_pattern(Language::German, "+ä").unwrap();
+        assert!(matches(&knowledge, "käfer"));
+        assert!(!matches(&knowledge, "kafka"));
+    }
+
+    #[test]
+    fn test_parse_pattern_invalid_position() {
+        for pattern in ["?e0", "?e6", "?e", "?ex", "?"] {
+            assert!(
+                parse_pattern(Language::English, pattern).is_err(),
+                "{:?} should be rejected",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_pattern_invalid_mask_length() {
+        for pattern in ["a..e", "a..e..", "e"] {
+            assert!(
+                parse_pattern(Language::English, pattern).is_err(),
+                "{:?} should be rejected",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_pattern_required_and_absent() {
+        for pattern in ["+e -e", "-e +e", "?e2 -e", "+ee -e .e..."] {
+            assert!(
+                parse_pattern(Language::English, pattern).is_err(),
+                "{:?} should be rejected",
+                pattern
+            );
+        }
+        assert!(parse_pattern(Language::English, "+e -e .e...").is_ok());
+    }
+}
@@ -1,11 +1,19 @@
 mod assistant;
+mod best_opener;
 mod common;
+mod diff;
+mod filter;
 mod simulate;
+mod solve;
+mod tree;
+mod verify;
 
 use clap::{Parser, Subcommand, ValueEnum};
 use color_eyre::eyre::Result;
 use common::AIType;
-use wordle_core::Language as CoreLanguage;
+use std::path::PathBuf;
+use tracing_subscriber::EnvFilter;
+use wordle_core::{DEFAULT_MAX_ATTEMPTS, Language as CoreLanguage};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum Language {
@@ -45,6 +53,118 @@ enum Commands {
         /// Language to play in
         #[arg(short, long, value_enum, default_value_t = Language::English)]
         language: Language,
+
+        /// Guesses already made, e.g. "slate=BBGYB;crane=BGBBB" (G: green, Y: yellow, B: black)
+        #[arg(long)]
+        history: Option<String>,
+
+        /// Ignore the oldest guesses instead of giving up when the feedback is inconsistent
+        #[arg(long)]
+        lenient: bool,
+
+        /// Word frequency file ("word count" per line) used to rank the last few candidates
+        #[arg(short, long)]
+        frequencies: Option<PathBuf>,
+    },
+    /// Let an AI solve a given word and show how long each guess took
+    Solve {
+        /// The word to solve
+        word: String,
+
+        /// Which AI agent to use
+        #[arg(short, long, value_enum, default_value_t = AIType::Heuristic)]
+        ai: AIType,
+
+        /// Language to play in
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+    },
+    /// Print all words matching a constraint pattern, sorted
+    Filter {
+        /// Whitespace-separated terms (quote the whole pattern):
+        /// `a..e.` letters at known positions (`.` unknown),
+        /// `+ei` letters present somewhere,
+        /// `-rst` absent letters,
+        /// `?e2` letter present but not at position 2 (1-5)
+        #[arg(allow_hyphen_values = true, verbatim_doc_comment)]
+        pattern: String,
+
+        /// Language of the word list
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+    },
+    /// Find the best opening words by playing every answer with each opener
+    BestOpener {
+        /// Which AI agent to use after the opener
+        #[arg(short, long, value_enum, default_value_t = AIType::Heuristic)]
+        ai: AIType,
+
+        /// Language to play in
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+
+        /// Number of best openers to report
+        #[arg(short, long, default_value_t = 10)]
+        top: usize,
+
+        /// Only try these openers (can specify multiple). Default: every word in the list
+        #[arg(short, long)]
+        opener: Vec<String>,
+
+        /// Seed for random agents' guesses, for reproducible runs. Default: a random seed
+        #[arg(short, long)]
+        seed: Option<u64>,
+    },
+    /// Run two AI agents on the same seeded games and list the words where they differ
+    Diff {
+        /// The agent to compare against
+        baseline: AIType,
+
+        /// The agent under test
+        candidate: AIType,
+
+        /// Language to play in
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+
+        /// Number of games to play
+        #[arg(short, long, default_value_t = 1000)]
+        num_games: usize,
+
+        /// Seed for choosing solutions and random agents' guesses. Default: a random seed
+        #[arg(short, long)]
+        seed: Option<u64>,
+    },
+    /// Export an AI's full solve tree from a given opener as JSON
+    Tree {
+        /// The first guess of every game
+        opener: String,
+
+        /// Which AI agent picks the guesses after the opener
+        #[arg(short, long, value_enum, default_value_t = AIType::Heuristic)]
+        ai: AIType,
+
+        /// Language to play in
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+
+        /// File to write the tree to. Default: stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Play an AI against every answer and list the ones it doesn't solve within the budget
+    Verify {
+        /// Which AI agent to verify
+        #[arg(short, long, value_enum, default_value_t = AIType::Heuristic)]
+        ai: AIType,
+
+        /// Language to play in
+        #[arg(short, long, value_enum, default_value_t = Language::English)]
+        language: Language,
+
+        /// Number of guesses every answer must be solved within
+        #[arg(short, long, default_value_t = DEFAULT_MAX_ATTEMPTS)]
+        max_attempts: usize,
     },
     /// Simulate games and compare AI performance
     Simulate {
@@ -53,13 +173,41 @@ enum Commands {
         num_games: usize,
 
         /// Which AI agents to test (can specify multiple)
-        /// Default: Random, RandomUpdates, Heuristic (Entropy excluded due to slowness)
+        /// Default: Random, RandomUpdates, Heuristic (Entropy and Elimination excluded due to slowness)
         #[arg(short, long, value_enum)]
         ai: Vec<AIType>,
 
         /// Language to play in
         #[arg(short, long, value_enum, default_value_t = Language::English)]
         language: Language,
+
+        /// Simulate every supported language and show the win rates side by side
+        #[arg(long, conflicts_with_all = ["language", "frequencies"])]
+        all_languages: bool,
+
+        /// Word frequency file ("word count" per line) used by the Entropy Guesser to break ties
+        #[arg(short, long)]
+        frequencies: Option<PathBuf>,
+
+        /// Seed for choosing solutions and random agents' guesses, for reproducible runs
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// Log every game's outcome and periodic per-AI aggregates (filter with RUST_LOG)
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Also write the results, including solved-within-N percentages, to this JSON file
+        #[arg(long)]
+        json: Option<PathBuf>,
+
+        /// Append one row per AI to this CSV file, creating it with a header if needed
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Print one summary line per AI instead of progress and the TUI, e.g. for CI
+        #[arg(short, long)]
+        quiet: bool,
     },
 }
 
@@ -68,23 +216,112 @@ fn main() -> Result<()> {
     let args = Args::parse();
 
     match args.command {
-        Commands::Assistant { ai, language } => {
-            assistant::run_assistant(ai, language.into())?;
+        Commands::Assistant {
+            ai,
+            language,
+            history,
+            lenient,
+            frequencies,
+        } => {
+            let language = language.into();
+            let history = history
+                .map(|history| common::parse_history(&history, language))
+                .transpose()?
+                .unwrap_or_default();
+            let frequencies = frequencies
+                .map(|path| common::load_frequencies(&path))
+                .transpose()?;
+            assistant::run_assistant(ai, language, history, lenient, frequencies)?;
+        }
+        Commands::Solve { word, ai, language } => {
+            solve::run_solve(&word, ai, language.into())?;
+        }
+        Commands::Filter { pattern, language } => {
+            filter::run_filter(language.into(), &pattern)?;
+        }
+        Commands::BestOpener {
+            ai,
+            language,
+            top,
+            opener,
+            seed,
+        } => {
+            best_opener::run_best_opener(ai, language.into(), top, opener, seed)?;
+        }
+        Commands::Diff {
+            baseline,
+            candidate,
+            language,
+            num_games,
+            seed,
+        } => {
+            diff::run_diff(baseline, candidate, language.into(), num_games, seed)?;
+        }
+        Commands::Tree {
+            opener,
+            ai,
+            language,
+            output,
+        } => {
+            tree::run_tree(ai, language.into(), &opener, output.as_deref())?;
+        }
+        Commands::Verify {
+            ai,
+            language,
+            max_attempts,
+        } => {
+            verify::run_verify(ai, language.into(), max_attempts)?;
         }
         Commands::Simulate {
             num_games,
             ai,
             language,
+            all_languages,
+            frequencies,
+            seed,
+            verbose,
+            json,
+            output,
+            quiet,
         } => {
+            if verbose {
+                init_tracing();
+            }
+
             // Default to fast AIs if none specified
             let ai_types = if ai.is_empty() {
                 vec![AIType::Random, AIType::RandomUpdates, AIType::Heuristic]
             } else {
                 ai
             };
-            simulate::run_simulation(num_games, ai_types, language.into())?;
+            let options = simulate::SimulationOptions {
+                num_games,
+                seed,
+                verbose,
+                quiet,
+            };
+            let files = simulate::ResultFiles {
+                json: json.as_deref(),
+                csv: output.as_deref(),
+            };
+            if all_languages {
+                return simulate::run_simulation_all_languages(ai_types, options, files);
+            }
+            let frequencies = frequencies
+                .map(|path| common::load_frequencies(&path))
+                .transpose()?;
+            simulate::run_simulation(ai_types, language.into(), frequencies, options, files)?;
         }
     }
 
     Ok(())
 }
+
+/// Log to stderr, at info level unless overridden by `RUST_LOG`
+fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
@@ -1,54 +1,121 @@
+use clap::ValueEnum;
 use color_eyre::eyre::Result;
 use ratatui::{
     DefaultTerminal, Frame,
     crossterm::event::{self, Event, KeyCode},
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
-    widgets::{BarChart, Block, Borders, Paragraph},
+    text::{Line, Span},
+    widgets::{BarChart, Block, Borders, Cell, Paragraph, Row, Table},
 };
 use rayon::prelude::*;
-use std::collections::HashMap;
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process::Command;
 use std::sync::{Arc, Mutex};
-use wordle_ai::WordleAI;
-use wordle_core::{Game, GuessResult};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, info, info_span};
+use wordle_ai::{PlayOutcome, WordleAI};
+use wordle_core::{DEFAULT_MAX_ATTEMPTS, Game, display_upper, format_feedback};
 
-use crate::common::{AIType, create_ai, get_wordlist};
+use crate::common::{AIOptions, AIType, create_ai, seeded_solution};
 use wordle_core::Language;
 
-const MAX_ATTEMPTS: usize = 6;
+/// Columns of the CSV file simulation runs are appended to
+const CSV_HEADER: &str = "timestamp,git_describe,language,ai,games,win_rate,avg_guesses,median_guesses,p90_guesses,p99_guesses";
 
+/// Guesses a lost game counts as in the ranking, one more than a game may take
+pub(crate) const LOSS_PENALTY: usize = DEFAULT_MAX_ATTEMPTS + 1;
+
+/// How a simulation is run
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationOptions {
+    /// Number of games every agent plays, per language
+    pub num_games: usize,
+    /// Seed that picks the solutions and the random agents' guesses, making a run reproducible
+    pub seed: Option<u64>,
+    /// Report progress through `tracing`, including per-AI aggregates and every game's outcome
+    /// (failures at info, wins at debug level)
+    pub verbose: bool,
+    /// Print nothing but one summary line per agent, instead of showing the results in the TUI
+    pub quiet: bool,
+}
+
+/// Files to write the results to, besides showing them
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResultFiles<'a> {
+    /// Every agent's results as JSON, replacing the file
+    pub json: Option<&'a Path>,
+    /// One row per agent and language, appended to the file to track results over time
+    pub csv: Option<&'a Path>,
+}
+
+/// One game an agent played, to show how it goes about solving
 #[derive(Debug, Clone)]
-struct AgentStats {
+struct ExampleGame {
+    solution: [char; 5],
+    outcome: PlayOutcome,
+}
+
+impl ExampleGame {
+    fn to_json(&self) -> Value {
+        let guesses: Vec<Value> = self
+            .outcome
+            .guesses
+            .iter()
+            .map(|(guess, result)| {
+                json!({
+                    "guess": guess.iter().collect::<String>(),
+                    "feedback": format_feedback(result),
+                })
+            })
+            .collect();
+        json!({
+            "solution": self.solution.iter().collect::<String>(),
+            "solved": self.outcome.solved,
+            "guesses": guesses,
+        })
+    }
+}
+
+/// One agent's results over a number of games
+#[derive(Debug, Clone)]
+pub(crate) struct AgentStats {
     ai_type: AIType,
     wins: usize,
     losses: usize,
     guess_distribution: HashMap<usize, usize>, // guesses -> count
     total_guesses: usize,                      // for calculating average
+    /// The first game of the run, the same for every agent
+    example: Option<ExampleGame>,
 }
 
 impl AgentStats {
-    fn new(ai_type: AIType) -> Self {
+    pub(crate) fn new(ai_type: AIType) -> Self {
         Self {
             ai_type,
             wins: 0,
             losses: 0,
             guess_distribution: HashMap::new(),
             total_guesses: 0,
+            example: None,
         }
     }
 
-    fn record_win(&mut self, num_guesses: usize) {
+    pub(crate) fn record_win(&mut self, num_guesses: usize) {
         self.wins += 1;
         self.total_guesses += num_guesses;
         *self.guess_distribution.entry(num_guesses).or_insert(0) += 1;
     }
 
-    fn record_loss(&mut self) {
+    pub(crate) fn record_loss(&mut self) {
         self.losses += 1;
     }
 
-    fn win_rate(&self) -> f64 {
+    pub(crate) fn win_rate(&self) -> f64 {
         if self.wins + self.losses == 0 {
             0.0
         } else {
@@ -64,6 +131,19 @@ impl AgentStats {
         }
     }
 
+    /// Average guesses over all games, where every lost game counts as `loss_penalty` guesses
+    ///
+    /// Lower is better. Unlike ranking by win rate first, this weighs a loss against the extra
+    /// guesses an agent spends elsewhere.
+    pub(crate) fn composite_score(&self, loss_penalty: usize) -> f64 {
+        let games = self.wins + self.losses;
+        if games == 0 {
+            0.0
+        } else {
+            (self.total_guesses + self.losses * loss_penalty) as f64 / games as f64
+        }
+    }
+
     fn min_guesses(&self) -> Option<usize> {
         self.guess_distribution.keys().min().copied()
     }
@@ -71,51 +151,317 @@ impl AgentStats {
     fn max_guesses(&self) -> Option<usize> {
         self.guess_distribution.keys().max().copied()
     }
-}
 
-/// Simulate a single game with a given AI
-fn simulate_game(ai: &mut Box<dyn WordleAI>, game: &Game) -> Option<usize> {
-    let mut num_guesses = 0;
-    let mut game = game.clone();
+    fn loss_rate(&self) -> f64 {
+        if self.wins + self.losses == 0 {
+            0.0
+        } else {
+            (self.losses as f64) / ((self.wins + self.losses) as f64) * 100.0
+        }
+    }
 
-    loop {
-        // Get AI's guess
-        let guess = ai.make_guess()?;
+    /// Number of guesses needed in at least `p` percent of the won games (nearest-rank method)
+    fn percentile(&self, p: f64) -> Option<usize> {
+        if self.wins == 0 {
+            return None;
+        }
 
-        num_guesses += 1;
+        let rank = ((p / 100.0 * self.wins as f64).ceil() as usize).clamp(1, self.wins);
+        let mut guesses: Vec<(&usize, &usize)> = self.guess_distribution.iter().collect();
+        guesses.sort();
 
-        // Submit guess to game
-        match game.take_guess(&guess) {
-            Ok(GuessResult::Won(_)) => {
+        let mut seen = 0;
+        for (&num_guesses, &count) in guesses {
+            seen += count;
+            if seen >= rank {
                 return Some(num_guesses);
             }
-            Ok(GuessResult::Lost { .. }) => {
-                return None;
-            }
-            Ok(GuessResult::Continue(result)) => {
-                // Update AI with feedback
-                ai.update(guess, result);
-            }
-            Err(_) => {
-                // Word not in list - mark as invalid and try again
-                ai.mark_invalid(guess);
-            }
         }
+        None
+    }
+
+    fn median_guesses(&self) -> Option<usize> {
+        self.percentile(50.0)
+    }
+
+    /// Number of guess counts worth showing: up to the max attempts or the largest count observed
+    fn num_guess_counts(&self) -> usize {
+        self.max_guesses().unwrap_or(0).max(DEFAULT_MAX_ATTEMPTS)
+    }
+
+    /// Percentage of all games solved within 1, 2, ... guesses
+    fn solved_within(&self) -> Vec<f64> {
+        let games = self.wins + self.losses;
+        let mut solved = 0;
+        (1..=self.num_guess_counts())
+            .map(|num_guesses| {
+                solved += self
+                    .guess_distribution
+                    .get(&num_guesses)
+                    .copied()
+                    .unwrap_or(0);
+                if games == 0 {
+                    0.0
+                } else {
+                    solved as f64 / games as f64 * 100.0
+                }
+            })
+            .collect()
+    }
+
+    /// Summary of the agent's results for machine-readable output
+    fn to_json(&self) -> Value {
+        let per_guess_count = |values: Vec<Value>| -> Map<String, Value> {
+            (1..).map(|n: usize| n.to_string()).zip(values).collect()
+        };
+        let distribution = (1..=self.num_guess_counts())
+            .map(|n| json!(self.guess_distribution.get(&n).copied().unwrap_or(0)))
+            .collect();
+        let solved_within = self.solved_within().into_iter().map(|p| json!(p)).collect();
+
+        json!({
+            "ai": self.ai_type.name(),
+            "wins": self.wins,
+            "losses": self.losses,
+            "win_rate": self.win_rate(),
+            "avg_guesses": self.avg_guesses(),
+            "median_guesses": self.median_guesses(),
+            "p90_guesses": self.percentile(90.0),
+            "p99_guesses": self.percentile(99.0),
+            "composite_score": self.composite_score(LOSS_PENALTY),
+            "guess_distribution": per_guess_count(distribution),
+            "solved_within_percent": per_guess_count(solved_within),
+            "example": self.example.as_ref().map(ExampleGame::to_json),
+        })
+    }
+}
+
+/// Write every agent's results as a JSON array, in the order the agents were given
+fn write_json(path: &Path, stats: &HashMap<AIType, AgentStats>, ai_types: &[AIType]) -> Result<()> {
+    let results: Vec<Value> = ai_types
+        .iter()
+        .filter_map(|ai_type| stats.get(ai_type))
+        .map(AgentStats::to_json)
+        .collect();
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &results)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write every language's results as a JSON array, each agent's entry naming its language
+fn write_json_all_languages(
+    path: &Path,
+    stats: &BTreeMap<Language, HashMap<AIType, AgentStats>>,
+    ai_types: &[AIType],
+) -> Result<()> {
+    let results: Vec<Value> = stats
+        .iter()
+        .flat_map(|(language, stats)| {
+            ai_types
+                .iter()
+                .filter_map(|ai_type| stats.get(ai_type))
+                .map(move |agent_stats| {
+                    let mut result = agent_stats.to_json();
+                    result["language"] = json!(format!("{:?}", language));
+                    result
+                })
+        })
+        .collect();
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &results)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Print one line per agent and language, like `heuristic language=English games=1000 ...`
+///
+/// Every value follows as `key=value` and the agent is named as on the command line, so the
+/// lines are easy to check in scripts.
+fn print_summary(stats: &[(Language, &HashMap<AIType, AgentStats>)], ai_types: &[AIType]) {
+    for (language, stats) in stats {
+        for agent_stats in ai_types.iter().filter_map(|ai_type| stats.get(ai_type)) {
+            let name = agent_stats.ai_type.to_possible_value().map_or_else(
+                || agent_stats.ai_type.name().to_string(),
+                |value| value.get_name().to_string(),
+            );
+            println!(
+                "{} language={:?} games={} wins={} losses={} win_rate={:.2} avg_guesses={:.4} composite_score={:.4}",
+                name,
+                language,
+                agent_stats.wins + agent_stats.losses,
+                agent_stats.wins,
+                agent_stats.losses,
+                agent_stats.win_rate(),
+                agent_stats.avg_guesses(),
+                agent_stats.composite_score(LOSS_PENALTY)
+            );
+        }
+    }
+}
+
+/// Append one CSV row per agent and language, writing the header first if the file is new
+///
+/// Rows are stamped with the current Unix time and, when run inside a git checkout, the
+/// output of `git describe`, so runs after different solver changes can be told apart.
+fn append_csv(
+    path: &Path,
+    stats: &[(Language, &HashMap<AIType, AgentStats>)],
+    ai_types: &[AIType],
+) -> Result<()> {
+    let is_new = !path.exists() || path.metadata()?.len() == 0;
+    let mut writer = BufWriter::new(OpenOptions::new().create(true).append(true).open(path)?);
+    if is_new {
+        writeln!(writer, "{}", CSV_HEADER)?;
+    }
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let git_describe = git_describe().unwrap_or_default();
+    for (language, stats) in stats {
+        for agent_stats in ai_types.iter().filter_map(|ai_type| stats.get(ai_type)) {
+            writeln!(
+                writer,
+                "{},{},{:?},{},{},{:.2},{:.4},{},{},{}",
+                timestamp,
+                csv_field(&git_describe),
+                language,
+                csv_field(agent_stats.ai_type.name()),
+                agent_stats.wins + agent_stats.losses,
+                agent_stats.win_rate(),
+                agent_stats.avg_guesses(),
+                optional_field(agent_stats.median_guesses()),
+                optional_field(agent_stats.percentile(90.0)),
+                optional_field(agent_stats.percentile(99.0))
+            )?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// The `git describe` of the working directory, if it is a git checkout
+fn git_describe() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
     }
 }
 
+/// A number for the CSV file, left empty if there is none
+fn optional_field(value: Option<usize>) -> String {
+    value.map(|n| n.to_string()).unwrap_or_default()
+}
+
+/// Simulate a single game with a given AI
+fn simulate_game(ai: &mut Box<dyn WordleAI>, game: &Game) -> PlayOutcome {
+    let mut game = game.clone();
+    wordle_ai::play(ai.as_mut(), &mut game)
+}
+
 /// Run simulation for specified AI agents (parallelized)
-pub fn run_simulation(num_games: usize, ai_types: Vec<AIType>, language: Language) -> Result<()> {
-    println!("Starting simulation of {} games...", num_games);
-    println!(
-        "Testing AI agents: {}",
-        ai_types
-            .iter()
-            .map(|ai| ai.name())
-            .collect::<Vec<_>>()
-            .join(", ")
-    );
+///
+/// The final results are also written to the given `files`.
+pub fn run_simulation(
+    ai_types: Vec<AIType>,
+    language: Language,
+    frequencies: Option<HashMap<[char; 5], f64>>,
+    options: SimulationOptions,
+    files: ResultFiles,
+) -> Result<()> {
+    let SimulationOptions {
+        num_games,
+        seed,
+        quiet,
+        ..
+    } = options;
+    if !quiet {
+        println!("Starting simulation of {} games...", num_games);
+        println!(
+            "Testing AI agents: {}",
+            ai_types
+                .iter()
+                .map(|ai| ai.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if let Some(seed) = seed {
+            println!("Using seed {}", seed);
+        }
+        if let Some(ref frequencies) = frequencies {
+            println!(
+                "Entropy and Human-like Guessers use {} word frequencies",
+                frequencies.len()
+            );
+        }
+    }
+
+    let final_stats = simulate_language(&ai_types, language, frequencies.as_ref(), options);
+    if !quiet {
+        println!("Simulation complete!");
+    }
+
+    for &ai_type in &ai_types {
+        let stats = &final_stats[&ai_type];
+        info!(
+            ai = ai_type.name(),
+            wins = stats.wins,
+            losses = stats.losses,
+            avg_guesses = stats.avg_guesses(),
+            "summary"
+        );
+    }
+
+    if let Some(path) = files.json {
+        write_json(path, &final_stats, &ai_types)?;
+        if !quiet {
+            println!("Results written to {}", path.display());
+        }
+    }
+    if let Some(path) = files.csv {
+        append_csv(path, &[(language, &final_stats)], &ai_types)?;
+        if !quiet {
+            println!("Results appended to {}", path.display());
+        }
+    }
+
+    if quiet {
+        print_summary(&[(language, &final_stats)], &ai_types);
+        return Ok(());
+    }
 
+    // Display results in TUI
+    let terminal = ratatui::init();
+    let result = display_results(terminal, final_stats, num_games, &ai_types);
+    ratatui::restore();
+    result
+}
+
+/// Play `num_games` games in one language with every AI (parallelized)
+fn simulate_language(
+    ai_types: &[AIType],
+    language: Language,
+    frequencies: Option<&HashMap<[char; 5], f64>>,
+    options: SimulationOptions,
+) -> HashMap<AIType, AgentStats> {
+    let SimulationOptions {
+        num_games,
+        seed,
+        verbose,
+        quiet,
+    } = options;
     // Initialize stats for each AI wrapped in Arc<Mutex>
     let all_stats: Arc<Mutex<HashMap<AIType, AgentStats>>> = Arc::new(Mutex::new(
         ai_types
@@ -128,50 +474,249 @@ pub fn run_simulation(num_games: usize, ai_types: Vec<AIType>, language: Languag
     let progress = Arc::new(Mutex::new(0usize));
 
     // Run simulations in parallel
-    (0..num_games).into_par_iter().for_each(|_| {
+    (0..num_games).into_par_iter().for_each(|game_index| {
         // Update progress
         {
             let mut p = progress.lock().unwrap();
             *p += 1;
             if (*p).is_multiple_of(100) {
-                println!("Progress: {}/{}", *p, num_games);
+                if verbose {
+                    info!(completed = *p, total = num_games, "progress");
+                    for stats in all_stats.lock().unwrap().values() {
+                        info!(
+                            ai = stats.ai_type.name(),
+                            wins = stats.wins,
+                            losses = stats.losses,
+                            avg_guesses = stats.avg_guesses(),
+                            "aggregate"
+                        );
+                    }
+                } else if !quiet {
+                    println!("Progress: {}/{}", *p, num_games);
+                }
             }
         }
 
-        let game = Game::new(MAX_ATTEMPTS, language).unwrap();
+        let _game_span = info_span!("game", index = game_index).entered();
+
+        // With a seed, every game is reproducible regardless of the order in which they run
+        let (solution, ai_seed) = match seed {
+            Some(seed) => {
+                let (solution, ai_seed) = seeded_solution(language, seed, game_index);
+                (solution, Some(ai_seed))
+            }
+            None => (language.random_word(), None),
+        };
+        let game = Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, solution).unwrap();
 
         // Each AI plays this game
-        for &ai_type in &ai_types {
-            let wordlist = get_wordlist(language).to_vec();
-            let mut ai = create_ai(ai_type, wordlist);
+        for &ai_type in ai_types {
+            let options = AIOptions {
+                seed: ai_seed,
+                frequencies,
+                ..AIOptions::default()
+            };
+            let mut ai = create_ai(ai_type, language, &options);
 
-            let result = simulate_game(&mut ai, &game);
+            let outcome = simulate_game(&mut ai, &game);
+            let guesses: Vec<String> = outcome
+                .guesses
+                .iter()
+                .map(|(guess, _)| guess.iter().collect())
+                .collect();
 
             // Update stats
             let mut stats = all_stats.lock().unwrap();
-            match result {
-                Some(num_guesses) => {
-                    stats.get_mut(&ai_type).unwrap().record_win(num_guesses);
-                }
-                None => {
-                    stats.get_mut(&ai_type).unwrap().record_loss();
-                }
+            let stats = stats.get_mut(&ai_type).unwrap();
+            if outcome.solved {
+                debug!(ai = ai_type.name(), ?guesses, "solved");
+                stats.record_win(outcome.guesses.len());
+            } else {
+                info!(ai = ai_type.name(), ?guesses, "failed");
+                stats.record_loss();
+            }
+            if game_index == 0 {
+                stats.example = Some(ExampleGame { solution, outcome });
             }
         }
     });
 
-    println!("Simulation complete!");
-
     // Extract stats from Arc<Mutex>
-    let final_stats = Arc::try_unwrap(all_stats).unwrap().into_inner().unwrap();
+    Arc::try_unwrap(all_stats).unwrap().into_inner().unwrap()
+}
+
+/// Run the simulation once per supported language and compare the agents across them
+///
+/// The same seed is used in every language. Each agent's results per language are also
+/// written to the given `files`. With `quiet`, the results are printed as by
+/// [`run_simulation`] instead of being shown in the TUI.
+pub fn run_simulation_all_languages(
+    ai_types: Vec<AIType>,
+    options: SimulationOptions,
+    files: ResultFiles,
+) -> Result<()> {
+    let SimulationOptions {
+        num_games,
+        seed,
+        quiet,
+        ..
+    } = options;
+    if !quiet {
+        println!(
+            "Starting simulation of {} games in each of {} languages...",
+            num_games,
+            Language::all().len()
+        );
+        println!(
+            "Testing AI agents: {}",
+            ai_types
+                .iter()
+                .map(|ai| ai.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if let Some(seed) = seed {
+            println!("Using seed {}", seed);
+        }
+    }
+
+    let mut all_stats = BTreeMap::new();
+    for &language in Language::all() {
+        if !quiet {
+            println!("Simulating {:?}...", language);
+        }
+        let _language_span = info_span!("language", ?language).entered();
+        let stats = simulate_language(&ai_types, language, None, options);
+        for &ai_type in &ai_types {
+            let stats = &stats[&ai_type];
+            info!(
+                ai = ai_type.name(),
+                wins = stats.wins,
+                losses = stats.losses,
+                avg_guesses = stats.avg_guesses(),
+                "summary"
+            );
+        }
+        all_stats.insert(language, stats);
+    }
+
+    if !quiet {
+        println!("Simulation complete!");
+    }
+
+    if let Some(path) = files.json {
+        write_json_all_languages(path, &all_stats, &ai_types)?;
+        if !quiet {
+            println!("Results written to {}", path.display());
+        }
+    }
+    let stats: Vec<_> = all_stats
+        .iter()
+        .map(|(&language, stats)| (language, stats))
+        .collect();
+    if let Some(path) = files.csv {
+        append_csv(path, &stats, &ai_types)?;
+        if !quiet {
+            println!("Results appended to {}", path.display());
+        }
+    }
+
+    if quiet {
+        print_summary(&stats, &ai_types);
+        return Ok(());
+    }
 
     // Display results in TUI
     let terminal = ratatui::init();
-    let result = display_results(terminal, final_stats, num_games, &ai_types);
+    let result = display_language_matrix(terminal, all_stats, num_games, &ai_types);
     ratatui::restore();
     result
 }
 
+fn display_language_matrix(
+    mut terminal: DefaultTerminal,
+    stats: BTreeMap<Language, HashMap<AIType, AgentStats>>,
+    num_games: usize,
+    ai_types: &[AIType],
+) -> Result<()> {
+    loop {
+        terminal.draw(|frame| render_language_matrix(frame, &stats, num_games, ai_types))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
+                    break Ok(());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Win rate and average guesses of every agent (rows) in every language (columns)
+fn render_language_matrix(
+    frame: &mut Frame,
+    stats: &BTreeMap<Language, HashMap<AIType, AgentStats>>,
+    num_games: usize,
+    ai_types: &[AIType],
+) {
+    let layout = Layout::vertical([
+        Constraint::Length(3), // Title
+        Constraint::Min(5),    // Matrix
+        Constraint::Length(3), // Help
+    ])
+    .split(frame.area());
+
+    let title = Paragraph::new(format!(
+        "SIMULATION RESULTS ({} games per language)",
+        num_games
+    ))
+    .style(Style::default().fg(Color::White).bold())
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(title, layout[0]);
+
+    let header = Row::new(
+        std::iter::once(Cell::from("AI")).chain(
+            stats
+                .keys()
+                .map(|language| Cell::from(format!("{:?}", language))),
+        ),
+    )
+    .style(Style::default().bold());
+
+    let rows = ai_types.iter().map(|&ai_type| {
+        let cells = stats.values().map(|stats| {
+            stats
+                .get(&ai_type)
+                .map(|agent_stats| {
+                    format!(
+                        "{:.1}% ({:.2})",
+                        agent_stats.win_rate(),
+                        agent_stats.avg_guesses()
+                    )
+                })
+                .unwrap_or_else(|| "N/A".to_string())
+        });
+        Row::new(std::iter::once(ai_type.name().to_string()).chain(cells))
+    });
+
+    let widths: Vec<Constraint> = std::iter::once(Constraint::Length(24))
+        .chain(stats.keys().map(|_| Constraint::Fill(1)))
+        .collect();
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Win rate (avg guesses)"),
+    );
+    frame.render_widget(table, layout[1]);
+
+    let help = Paragraph::new("Press Q or Esc to quit")
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(help, layout[2]);
+}
+
 fn display_results(
     mut terminal: DefaultTerminal,
     stats: HashMap<AIType, AgentStats>,
@@ -202,6 +747,7 @@ fn render(
 
     let layout = Layout::vertical([
         Constraint::Length(3), // Title
+        Constraint::Length(3), // Ranking
         Constraint::Min(10),   // Stats
         Constraint::Length(3), // Help
     ])
@@ -214,6 +760,9 @@ fn render(
         .block(Block::default().borders(Borders::ALL));
     frame.render_widget(title, layout[0]);
 
+    // Ranking of all agents, best first
+    render_ranking(frame, layout[1], stats, ai_types);
+
     // Stats - split horizontally for each AI (dynamically)
     let num_agents = ai_types.len();
 
@@ -222,7 +771,7 @@ fn render(
         .map(|_| Constraint::Ratio(1, num_agents as u32))
         .collect();
 
-    let stats_layout = Layout::horizontal(constraints).split(layout[1]);
+    let stats_layout = Layout::horizontal(constraints).split(layout[2]);
 
     // Render each agent's stats (supports any number of agents)
     for (i, &ai_type) in ai_types.iter().enumerate() {
@@ -235,13 +784,60 @@ fn render(
     let help = Paragraph::new("Press Q or Esc to quit")
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
-    frame.render_widget(help, layout[2]);
+    frame.render_widget(help, layout[3]);
+}
+
+fn render_ranking(
+    frame: &mut Frame,
+    area: Rect,
+    stats: &HashMap<AIType, AgentStats>,
+    ai_types: &[AIType],
+) {
+    // Rank by average guesses with losses counted as LOSS_PENALTY guesses
+    let mut ranked: Vec<&AgentStats> = ai_types
+        .iter()
+        .filter_map(|ai_type| stats.get(ai_type))
+        .collect();
+    ranked.sort_by(|a, b| {
+        a.composite_score(LOSS_PENALTY)
+            .total_cmp(&b.composite_score(LOSS_PENALTY))
+    });
+
+    let mut spans = Vec::new();
+    for (rank, agent_stats) in ranked.iter().enumerate() {
+        if rank > 0 {
+            spans.push(Span::raw("  |  "));
+        }
+        let entry = format!(
+            "{}. {} ({:.2}, {:.1}%)",
+            rank + 1,
+            agent_stats.ai_type.name(),
+            agent_stats.composite_score(LOSS_PENALTY),
+            agent_stats.win_rate()
+        );
+        // Highlight the winner
+        spans.push(if rank == 0 {
+            Span::styled(entry, Style::default().fg(Color::Green).bold())
+        } else {
+            Span::raw(entry)
+        });
+    }
+
+    let ranking = Paragraph::new(Line::from(spans))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Ranking (avg guesses with a loss as {}, win rate)",
+            LOSS_PENALTY
+        )));
+    frame.render_widget(ranking, area);
 }
 
 fn render_agent_stats(frame: &mut Frame, area: Rect, stats: &AgentStats) {
     let layout = Layout::vertical([
-        Constraint::Length(10), // Stats text
-        Constraint::Min(5),     // Chart
+        Constraint::Length(10),                              // Stats text
+        Constraint::Length(DEFAULT_MAX_ATTEMPTS as u16 + 2), // Example game
+        Constraint::Min(5),                                  // Chart
+        Constraint::Min(5),                                  // Cumulative chart
     ])
     .split(area);
 
@@ -256,14 +852,34 @@ fn render_agent_stats(frame: &mut Frame, area: Rect, stats: &AgentStats) {
         .max_guesses()
         .map(|n| n.to_string())
         .unwrap_or_else(|| "N/A".to_string());
+    let median_guesses = stats
+        .median_guesses()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| "N/A".to_string());
+    let percentile = |p: f64| {
+        stats
+            .percentile(p)
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "N/A".to_string())
+    };
 
     let text = vec![
         Line::from(""),
         Line::from(format!("Wins: {} ({:.1}%)", stats.wins, win_rate)),
-        Line::from(format!("Losses: {}", stats.losses)),
+        Line::from(format!(
+            "Losses: {} ({:.1}%)",
+            stats.losses,
+            stats.loss_rate()
+        )),
         Line::from(""),
         Line::from(format!("Avg guesses: {:.2}", avg_guesses)),
         Line::from(format!("Min: {} | Max: {}", min_guesses, max_guesses)),
+        Line::from(format!(
+            "Median: {} | P90: {} | P99: {}",
+            median_guesses,
+            percentile(90.0),
+            percentile(99.0)
+        )),
     ];
 
     let stats_widget = Paragraph::new(text).alignment(Alignment::Center).block(
@@ -274,27 +890,52 @@ fn render_agent_stats(frame: &mut Frame, area: Rect, stats: &AgentStats) {
 
     frame.render_widget(stats_widget, layout[0]);
 
+    // The first game's board
+    if let Some(example) = &stats.example {
+        render_example(frame, layout[1], example);
+    }
+
     // Chart - guess distribution
-    render_chart(frame, layout[1], stats);
+    render_chart(frame, layout[2], stats);
+
+    // Chart - percentage of games solved within N guesses
+    render_cumulative_chart(frame, layout[3], stats);
 }
 
-fn render_chart(frame: &mut Frame, area: Rect, stats: &AgentStats) {
-    // Prepare data for bar chart
-    let mut data: Vec<(&str, u64)> = Vec::new();
+fn render_example(frame: &mut Frame, area: Rect, example: &ExampleGame) {
+    let rows: Vec<Line> = example
+        .outcome
+        .guesses
+        .iter()
+        .map(|(guess, result)| wordle_tui::word_row(guess, result))
+        .collect();
+    let solution: String = example.solution.iter().map(|&c| display_upper(c)).collect();
+    let title = if example.outcome.solved {
+        format!("Example: {}", solution)
+    } else {
+        format!("Example: {} (lost)", solution)
+    };
 
-    // Create labels for 1-6 guesses
-    let labels = ["1", "2", "3", "4", "5", "6"];
-    let mut counts = [0u64; 6];
+    let board = Paragraph::new(rows)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(board, area);
+}
 
-    for guess_num in 1..=6 {
-        if let Some(&count) = stats.guess_distribution.get(&guess_num) {
-            counts[guess_num - 1] = count as u64;
-        }
-    }
+fn render_chart(frame: &mut Frame, area: Rect, stats: &AgentStats) {
+    // One bar per guess count, up to the max attempts or the largest count observed
+    let num_bars = stats.num_guess_counts();
+    let labels: Vec<String> = (1..=num_bars).map(|n| n.to_string()).collect();
 
-    for (i, &label) in labels.iter().enumerate() {
-        data.push((label, counts[i]));
-    }
+    // Prepare data for bar chart
+    let data: Vec<(&str, u64)> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let count = stats.guess_distribution.get(&(i + 1)).copied().unwrap_or(0);
+            (label.as_str(), count as u64)
+        })
+        .collect();
 
     let chart = BarChart::default()
         .block(
@@ -310,3 +951,132 @@ fn render_chart(frame: &mut Frame, area: Rect, stats: &AgentStats) {
 
     frame.render_widget(chart, area);
 }
+
+fn render_cumulative_chart(frame: &mut Frame, area: Rect, stats: &AgentStats) {
+    let labels: Vec<String> = (1..=stats.num_guess_counts())
+        .map(|n| format!("≤{}", n))
+        .collect();
+
+    // Bar heights are whole percentages of all games, losses included
+    let data: Vec<(&str, u64)> = labels
+        .iter()
+        .zip(stats.solved_within())
+        .map(|(label, percent)| (label.as_str(), percent.round() as u64))
+        .collect();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Solved within N guesses (%)"),
+        )
+        .data(&data)
+        .max(100)
+        .bar_width(3)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Yellow))
+        .value_style(Style::default().fg(Color::Black).bold());
+
+    frame.render_widget(chart, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stats with the given number of wins per guess count and no losses
+    fn stats_with(wins: &[(usize, usize)]) -> AgentStats {
+        let mut stats = AgentStats::new(AIType::Heuristic);
+        for &(num_guesses, count) in wins {
+            for _ in 0..count {
+                stats.record_win(num_guesses);
+            }
+        }
+        stats
+    }
+
+    #[test]
+    fn test_percentile() {
+        // 10 wins: 1 in two guesses, 4 in three, 4 in four, 1 in six
+        let stats = stats_with(&[(2, 1), (3, 4), (4, 4), (6, 1)]);
+        assert_eq!(stats.percentile(0.0), Some(2));
+        assert_eq!(stats.percentile(10.0), Some(2));
+        assert_eq!(stats.percentile(11.0), Some(3));
+        assert_eq!(stats.percentile(50.0), Some(3));
+        assert_eq!(stats.percentile(51.0), Some(4));
+        assert_eq!(stats.percentile(90.0), Some(4));
+        assert_eq!(stats.percentile(99.0), Some(6));
+        assert_eq!(stats.percentile(100.0), Some(6));
+    }
+
+    #[test]
+    fn test_percentile_ignores_losses() {
+        let mut stats = stats_with(&[(3, 1), (5, 1)]);
+        stats.record_loss();
+        stats.record_loss();
+        assert_eq!(stats.percentile(0.0), Some(3));
+        assert_eq!(stats.percentile(100.0), Some(5));
+    }
+
+    #[test]
+    fn test_percentile_without_wins() {
+        let stats = stats_with(&[]);
+        assert_eq!(stats.percentile(0.0), None);
+        assert_eq!(stats.percentile(100.0), None);
+        assert_eq!(stats.median_guesses(), None);
+
+        let mut stats = stats_with(&[]);
+        stats.record_loss();
+        assert_eq!(stats.percentile(50.0), None);
+        assert_eq!(stats.median_guesses(), None);
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("v1.2-3-gabc"), "v1.2-3-gabc");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("one\ntwo"), "\"one\ntwo\"");
+        assert_eq!(csv_field("one\r\ntwo"), "\"one\r\ntwo\"");
+    }
+
+    #[test]
+    fn test_append_csv_writes_header_once() {
+        let path = std::env::temp_dir().join(format!("wordle_ai_cli_csv_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let ai_types = [AIType::Heuristic];
+        let stats: HashMap<AIType, AgentStats> =
+            HashMap::from([(AIType::Heuristic, stats_with(&[(3, 2), (4, 1)]))]);
+        let stats = [(Language::English, &stats)];
+        append_csv(&path, &stats, &ai_types).unwrap();
+        append_csv(&path, &stats, &ai_types).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines.iter().filter(|&&line| line == CSV_HEADER).count(), 1);
+        for line in &lines[1..] {
+            assert!(
+                line.ends_with(",English,Heuristic Guesser,3,100.00,3.3333,3,4,4"),
+                "{}",
+                line
+            );
+            assert_eq!(line.split(',').count(), CSV_HEADER.split(',').count());
+        }
+    }
+
+    #[test]
+    fn test_median_guesses() {
+        assert_eq!(stats_with(&[(4, 1)]).median_guesses(), Some(4));
+        // With an even number of wins, the lower of the two middle values
+        assert_eq!(stats_with(&[(3, 2), (5, 2)]).median_guesses(), Some(3));
+        assert_eq!(
+            stats_with(&[(2, 1), (3, 1), (6, 3)]).median_guesses(),
+            Some(6)
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use color_eyre::eyre::{Result, eyre};
+use std::time::{Duration, Instant};
+use wordle_ai::{Knowledge, WordleAI};
+use wordle_core::{DEFAULT_MAX_ATTEMPTS, Game, Language, LetterResult};
+
+use crate::common::{AIOptions, AIType, create_ai, parse_word};
+
+/// Wraps an AI and records how long each of its guesses took to compute
+struct TimedAI<'a> {
+    ai: &'a mut dyn WordleAI,
+    timings: Vec<([char; 5], Duration)>,
+}
+
+impl WordleAI for TimedAI<'_> {
+    fn make_guess(&mut self) -> Option<[char; 5]> {
+        let start = Instant::now();
+        let guess = self.ai.make_guess();
+        if let Some(word) = guess {
+            self.timings.push((word, start.elapsed()));
+        }
+        guess
+    }
+
+    fn update(&mut self, guess: [char; 5], result: [LetterResult; 5]) {
+        self.ai.update(guess, result);
+    }
+
+    fn mark_invalid(&mut self, word: [char; 5]) {
+        self.ai.mark_invalid(word);
+    }
+
+    fn reset(&mut self) {
+        self.ai.reset();
+        self.timings.clear();
+    }
+
+    /// The clone is the wrapped AI alone, its guesses are not timed
+    fn box_clone(&self) -> Box<dyn WordleAI> {
+        self.ai.box_clone()
+    }
+
+    fn name(&self) -> &'static str {
+        self.ai.name()
+    }
+
+    fn knowledge(&self) -> Option<&Knowledge> {
+        self.ai.knowledge()
+    }
+}
+
+/// Let an AI solve a given word and print every guess with the time it took
+pub fn run_solve(word: &str, ai_type: AIType, language: Language) -> Result<()> {
+    let solution = parse_word(word, language)?;
+    let mut game = Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, solution)
+        .map_err(|_| eyre!("'{}' is not in the {:?} word list", word, language))?;
+
+    let mut ai = create_ai(ai_type, language, &AIOptions::default());
+    let mut timed = TimedAI {
+        ai: ai.as_mut(),
+        timings: Vec::new(),
+    };
+    let outcome = wordle_ai::play(&mut timed, &mut game);
+
+    println!("{} solving '{}':", ai_type.name(), word.to_lowercase());
+
+    // Rejected words are timed too, but don't show up in the outcome's guesses
+    let mut accepted = outcome.guesses.iter().peekable();
+    for (guess, duration) in &timed.timings {
+        let guess_str: String = guess.iter().collect();
+        match accepted.next_if(|(accepted_guess, _)| accepted_guess == guess) {
+            Some((_, result)) => {
+                println!("  {} {} ({:.2?})", guess_str, pattern_str(result), duration)
+            }
+            None => println!("  {} not in word list ({:.2?})", guess_str, duration),
+        }
+    }
+
+    let total: Duration = timed.timings.iter().map(|(_, duration)| *duration).sum();
+    if outcome.solved {
+        println!(
+            "Solved in {} guesses ({:.2?} total)",
+            outcome.guesses.len(),
+            total
+        );
+    } else {
+        println!("Failed to solve ({:.2?} total)", total);
+    }
+
+    Ok(())
+}
+
+/// Render feedback as colored squares, like the shareable Wordle grid
+fn pattern_str(result: &[LetterResult; 5]) -> String {
+    result
+        .iter()
+        .map(|r| match r {
+            LetterResult::Correct => '🟩',
+            LetterResult::Misplaced => '🟨',
+            LetterResult::Absent => '⬛',
+        })
+        .collect()
+}
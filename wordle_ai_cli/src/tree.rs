@@ -0,0 +1,46 @@
+use color_eyre::eyre::{Result, bail};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use wordle_core::Language;
+
+use crate::common::{AIOptions, AIType, create_ai, get_wordlist, parse_word};
+
+/// Build the AI's full solve tree from the given opener and write it as JSON
+///
+/// The tree goes to `output` if given, otherwise to stdout.
+pub fn run_tree(
+    ai_type: AIType,
+    language: Language,
+    opener: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let opener = parse_word(opener, language)?;
+    if !language.contains(&opener) {
+        bail!(
+            "'{}' is not in the {:?} word list",
+            opener.iter().collect::<String>(),
+            language
+        );
+    }
+
+    let wordlist = get_wordlist(language);
+    let mut ai = create_ai(ai_type, language, &AIOptions::default());
+    let tree = wordle_ai::build_decision_tree(ai.as_mut(), opener, wordlist);
+    eprintln!(
+        "Built tree for {} words, at most {} guesses needed",
+        wordlist.len(),
+        tree.max_guesses()
+    );
+
+    match output {
+        Some(path) => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            serde_json::to_writer(&mut writer, &tree)?;
+            writer.flush()?;
+        }
+        None => println!("{}", serde_json::to_string(&tree)?),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use color_eyre::eyre::{Result, bail};
+use rayon::prelude::*;
+use wordle_core::{Game, Language, LetterResult};
+
+use crate::common::{AIOptions, AIType, create_ai, get_wordlist};
+
+/// One answer the AI did not solve, with the guesses it made
+struct Failure {
+    answer: [char; 5],
+    guesses: Vec<([char; 5], [LetterResult; 5])>,
+}
+
+/// Play an AI against every answer of the language and list the answers it doesn't solve
+/// within `max_attempts` guesses (parallelized)
+///
+/// Unlike `simulate`, this is exhaustive, so passing proves the AI's worst case for this
+/// word list. Fails if any answer is not solved.
+pub fn run_verify(ai_type: AIType, language: Language, max_attempts: usize) -> Result<()> {
+    let wordlist = get_wordlist(language);
+    println!(
+        "Verifying {} against all {} answers within {} guesses...",
+        ai_type.name(),
+        wordlist.len(),
+        max_attempts
+    );
+
+    // Each answer's number of guesses if solved, or its failure
+    let results: Vec<std::result::Result<usize, Failure>> = wordlist
+        .par_iter()
+        .map_init(
+            || create_ai(ai_type, language, &AIOptions::default()),
+            |ai, &answer| {
+                ai.reset();
+                let mut game = Game::new_with_word(max_attempts, language, answer)
+                    .expect("answers are taken from the word list");
+                let outcome = wordle_ai::play(ai.as_mut(), &mut game);
+                if outcome.solved {
+                    Ok(outcome.guesses.len())
+                } else {
+                    Err(Failure {
+                        answer,
+                        guesses: outcome.guesses,
+                    })
+                }
+            },
+        )
+        .collect();
+
+    let worst_case = results.iter().flatten().max().copied();
+    let mut failures: Vec<Failure> = results.into_iter().filter_map(Result::err).collect();
+    failures.sort_by_key(|failure| failure.answer);
+
+    println!();
+    if failures.is_empty() {
+        println!(
+            "Solved all {} answers, needing at most {} guesses",
+            wordlist.len(),
+            worst_case.unwrap_or(0)
+        );
+        return Ok(());
+    }
+
+    println!("Unsolved answers:");
+    for failure in &failures {
+        let guesses: Vec<String> = failure
+            .guesses
+            .iter()
+            .map(|(guess, _)| guess.iter().collect())
+            .collect();
+        println!(
+            "{}: {}",
+            failure.answer.iter().collect::<String>(),
+            guesses.join(" ")
+        );
+    }
+    bail!(
+        "{} failed {} of {} answers within {} guesses",
+        ai_type.name(),
+        failures.len(),
+        wordlist.len(),
+        max_attempts
+    );
+}
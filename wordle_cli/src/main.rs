@@ -1,3 +1,5 @@
+mod stats;
+
 use clap::{Parser, ValueEnum};
 use color_eyre::eyre::Result;
 use ratatui::{
@@ -8,10 +10,14 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
-use wordle_core::{GameError, GuessResult, Language as CoreLanguage, LetterResult};
-
-const MAX_ATTEMPTS: usize = 6;
-const WORD_LENGTH: usize = 5;
+use stats::WordStats;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use wordle_ai::{GuessReview, HeuristicGuesser, WordleAI, review_guesses};
+use wordle_core::{
+    DEFAULT_MAX_ATTEMPTS, GameError, GuessResult, Language as CoreLanguage, LetterResult,
+    WORD_LENGTH, display_upper, lowercase_letter, normalize_guess,
+};
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum Language {
@@ -39,11 +45,41 @@ struct Args {
     /// Language to play in
     #[arg(short, long, value_enum, default_value_t = Language::English)]
     language: Language,
+
+    /// Secret word to use instead of a random one (two-player mode)
+    #[arg(short, long)]
+    word: Option<String>,
+
+    /// Pick the secret word from this seed, to play the same game as with `?seed=` on the web
+    #[arg(short, long, conflicts_with = "word")]
+    seed: Option<u64>,
+
+    /// Play today's puzzle (by UTC date), the same word for everyone on the same day
+    #[arg(short, long, conflicts_with_all = ["word", "seed"])]
+    daily: bool,
+
+    /// Prefer secret words you lost or needed many guesses for in earlier games
+    #[arg(short, long, conflicts_with_all = ["word", "seed", "daily"])]
+    practice: bool,
+
+    /// Start the next word right after every win, counting the words solved in a row
+    #[arg(short, long, conflicts_with_all = ["word", "daily"])]
+    endless: bool,
+
+    /// File your results per word are kept in (default: ~/.wordle_cli_stats)
+    #[arg(long)]
+    stats_file: Option<PathBuf>,
 }
 
 enum GameOutcome {
-    Won,
-    Lost { solution: [char; 5] },
+    Won {
+        /// How hard the solution was, see `Language::describe_difficulty`
+        difficulty: String,
+    },
+    Lost {
+        solution: [char; 5],
+        difficulty: String,
+    },
 }
 
 struct App {
@@ -52,20 +88,145 @@ struct App {
     current_input: Vec<char>,
     error_message: Option<String>,
     outcome: Option<GameOutcome>,
+    /// Suggested next guess, once the player asked for their hint
+    hint: Option<[char; 5]>,
+    hint_used: bool,
+    /// Seed the secret word was picked from, shown so the game can be shared
+    seed: Option<u64>,
+    /// Day number of the daily puzzle being played, see `Game::daily`
+    daily: Option<u64>,
+    /// Results per word, updated after every game
+    stats: Option<WordStats>,
+    /// Whether new games pick words the player struggled with
+    practice: bool,
+    /// Whether a win starts the next game right away
+    endless: bool,
+    /// Words solved in a row in endless mode
+    score: u32,
+    /// The previous word and the guesses it took, in endless mode
+    last_solved: Option<([char; 5], usize)>,
+    /// Every guess rated against the AI's choice, once the player asked for it after the game
+    review: Option<Vec<GuessReview>>,
 }
 
 impl App {
     fn new_game(language: CoreLanguage) -> Result<Self> {
-        let game = wordle_core::Game::new(MAX_ATTEMPTS, language)
+        let game = wordle_core::Game::new(DEFAULT_MAX_ATTEMPTS, language)
+            .map_err(|_| color_eyre::eyre::eyre!("Failed to create game"))?;
+
+        Ok(Self::from_game(game))
+    }
+
+    fn new_game_seeded(language: CoreLanguage, seed: u64) -> Result<Self> {
+        let game = wordle_core::Game::new_with_seed(DEFAULT_MAX_ATTEMPTS, language, seed)
+            .map_err(|_| color_eyre::eyre::eyre!("Failed to create game"))?;
+
+        Ok(Self {
+            seed: Some(seed),
+            ..Self::from_game(game)
+        })
+    }
+
+    fn new_daily_game(language: CoreLanguage, day: u64) -> Result<Self> {
+        let game = wordle_core::Game::daily(language, day)
             .map_err(|_| color_eyre::eyre::eyre!("Failed to create game"))?;
 
         Ok(Self {
+            daily: Some(day),
+            ..Self::from_game(game)
+        })
+    }
+
+    /// A game with a word the player did badly on before, or any word if there is none
+    fn new_practice_game(language: CoreLanguage, stats: &WordStats) -> Result<Self> {
+        let game = wordle_core::Game::new_weighted(DEFAULT_MAX_ATTEMPTS, language, |word| {
+            stats.practice_weight(word)
+        })
+        .or_else(|_| wordle_core::Game::new(DEFAULT_MAX_ATTEMPTS, language))
+        .map_err(|_| color_eyre::eyre::eyre!("Failed to create game"))?;
+
+        Ok(Self {
+            practice: true,
+            ..Self::from_game(game)
+        })
+    }
+
+    fn new_game_with_word(language: CoreLanguage, word: &str) -> Result<Self> {
+        let word = normalize_guess(word, language).map_err(|err| match err {
+            GameError::InvalidChar(c) => {
+                color_eyre::eyre::eyre!("Secret word contains '{}', which is not a letter", c)
+            }
+            _ => color_eyre::eyre::eyre!("Secret word must be 5 letters"),
+        })?;
+        let game = wordle_core::Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, word).map_err(
+            |_| {
+                color_eyre::eyre::eyre!(
+                    "Secret word '{}' is not in the {:?} word list",
+                    word.iter().collect::<String>(),
+                    language
+                )
+            },
+        )?;
+
+        Ok(Self::from_game(game))
+    }
+
+    fn from_game(game: wordle_core::Game) -> Self {
+        Self {
             game,
             guesses: Vec::new(),
             current_input: Vec::new(),
             error_message: None,
             outcome: None,
-        })
+            hint: None,
+            hint_used: false,
+            seed: None,
+            daily: None,
+            stats: None,
+            practice: false,
+            endless: false,
+            score: 0,
+            last_solved: None,
+            review: None,
+        }
+    }
+
+    /// Start the next game, keeping the stats, practice and endless mode
+    fn restart(&mut self) -> Result<()> {
+        let language = self.game.language();
+        let stats = self.stats.take();
+        let mut app = match &stats {
+            Some(stats) if self.practice => Self::new_practice_game(language, stats)?,
+            _ => Self::new_game(language)?,
+        };
+        app.stats = stats;
+        app.endless = self.endless;
+        *self = app;
+        Ok(())
+    }
+
+    /// In endless mode, go on with the next word after a win, keeping the score
+    fn continue_endless(&mut self, solution: [char; 5]) -> Result<()> {
+        let score = self.score + 1;
+        let solved = (solution, self.guesses.len());
+        // Keep a failure to save the stats visible in the next game
+        let error_message = self.error_message.take();
+
+        self.restart()?;
+        self.score = score;
+        self.last_solved = Some(solved);
+        self.error_message = error_message;
+        Ok(())
+    }
+
+    /// Record the finished game's result for its solution and save the stats
+    fn record_result(&mut self, solution: [char; 5], guesses: Option<usize>) {
+        if let Some(stats) = &mut self.stats {
+            stats.record(solution, guesses);
+            if let Err(err) = stats.save() {
+                self.error_message = Some(format!("Could not save stats: {}", err));
+            }
+        }
     }
 
     fn is_playing(&self) -> bool {
@@ -78,10 +239,9 @@ impl App {
         }
 
         match key.code {
-            KeyCode::Char(c) if c.is_alphabetic() && self.is_playing() => {
+            KeyCode::Char(c) if self.game.language().is_valid_char(c) && self.is_playing() => {
                 if self.current_input.len() < WORD_LENGTH {
-                    self.current_input
-                        .push(c.to_lowercase().next().unwrap_or(c));
+                    self.current_input.push(lowercase_letter(c));
                     self.error_message = None;
                 }
             }
@@ -92,33 +252,75 @@ impl App {
             KeyCode::Enter if self.is_playing() => {
                 self.submit_guess();
             }
+            // Not bound to a letter like 'h', as letters are typed into the guess above
+            KeyCode::Char('?') if self.is_playing() => {
+                self.reveal_hint();
+            }
             KeyCode::Char('r') | KeyCode::Char('R') if !self.is_playing() => {
-                let language = self.game.language();
-                *self = Self::new_game(language).unwrap();
+                self.restart().unwrap();
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') if !self.is_playing() => {
+                self.toggle_review();
             }
             _ => {}
         }
     }
 
-    fn submit_guess(&mut self) {
-        if self.current_input.len() != WORD_LENGTH {
-            self.error_message = Some("Word must be 5 letters".to_string());
+    /// Show or hide how every guess compares to the AI's choice, computing it the first time
+    fn toggle_review(&mut self) {
+        if self.review.take().is_none() {
+            self.review = Some(review_guesses(self.game.language(), &self.guesses));
+        }
+    }
+
+    /// Suggest a next guess from the AI, replaying the guesses made so far (once per game)
+    fn reveal_hint(&mut self) {
+        if self.hint_used {
+            self.error_message = Some("Only one hint per game".to_string());
             return;
         }
 
-        let guess: [char; 5] = self.current_input[..5].try_into().unwrap();
+        let mut ai = HeuristicGuesser::new(self.game.language().wordlist_array().to_vec());
+        for &(guess, result) in &self.guesses {
+            ai.update(guess, result);
+        }
+
+        self.hint = ai.make_guess();
+        self.hint_used = true;
+        if self.hint.is_none() {
+            self.error_message = Some("No hint available".to_string());
+        }
+    }
+
+    fn submit_guess(&mut self) {
+        let input: String = self.current_input.iter().collect();
+        let guess = match normalize_guess(&input, self.game.language()) {
+            Ok(guess) => guess,
+            Err(err) => {
+                self.error_message = Some(guess_error_message(&err));
+                return;
+            }
+        };
 
         match self.game.take_guess(&guess) {
             Ok(GuessResult::Continue(result)) => {
                 self.guesses.push((guess, result));
                 self.current_input.clear();
                 self.error_message = None;
+                // The hint was for the previous guess
+                self.hint = None;
             }
             Ok(GuessResult::Won(result)) => {
                 self.guesses.push((guess, result));
                 self.current_input.clear();
                 self.error_message = None;
-                self.outcome = Some(GameOutcome::Won);
+                self.outcome = Some(GameOutcome::Won {
+                    difficulty: self.game.language().describe_difficulty(&guess),
+                });
+                self.record_result(guess, Some(self.guesses.len()));
+                if self.endless {
+                    self.continue_endless(guess).unwrap();
+                }
             }
             Ok(GuessResult::Lost {
                 last_guess,
@@ -127,21 +329,26 @@ impl App {
                 self.guesses.push((guess, last_guess));
                 self.current_input.clear();
                 self.error_message = None;
-                self.outcome = Some(GameOutcome::Lost { solution });
+                self.outcome = Some(GameOutcome::Lost {
+                    solution,
+                    difficulty: self.game.language().describe_difficulty(&solution),
+                });
+                self.record_result(solution, None);
             }
-            Err(GameError::WordNotInList) => {
-                self.error_message = Some("Word not in list".to_string());
+            Err(err) => {
+                self.error_message = Some(guess_error_message(&err));
             }
         }
     }
 }
 
-/// Display a character in uppercase, but preserve ß instead of converting to SS
-fn uppercase_display(c: char) -> char {
-    if c == 'ß' {
-        c
-    } else {
-        c.to_uppercase().next().unwrap_or(c)
+fn guess_error_message(err: &GameError) -> String {
+    match err {
+        GameError::WordNotInList => "Word not in list".to_string(),
+        GameError::GameOver => "The game is already over".to_string(),
+        GameError::WrongLength => "Word must be 5 letters".to_string(),
+        GameError::InvalidChar(c) => format!("'{}' is not a letter", display_upper(*c)),
+        GameError::ViolatesHardMode => "Hard mode: use every hint found so far".to_string(),
     }
 }
 
@@ -151,15 +358,46 @@ fn main() -> Result<()> {
     let args = Args::parse();
     let language = args.language.into();
 
+    // Only practice mode needs the stats, other games are played without them if they can't
+    // be loaded
+    let (stats, stats_error) = match args.stats_file.or_else(WordStats::default_path) {
+        Some(path) => match WordStats::load(path) {
+            Ok(stats) => (Some(stats), None),
+            Err(err) if !args.practice => (None, Some(format!("Could not load stats: {}", err))),
+            Err(err) => return Err(err),
+        },
+        None => (None, None),
+    };
+    if args.practice && stats.is_none() {
+        color_eyre::eyre::bail!("Practice mode needs a stats file, please pass --stats-file");
+    }
+
+    // Validate the secret word before taking over the terminal
+    let mut app = match (args.word, args.seed, &stats) {
+        (Some(word), _, _) => App::new_game_with_word(language, &word)?,
+        (None, Some(seed), _) => App::new_game_seeded(language, seed)?,
+        (None, None, _) if args.daily => App::new_daily_game(language, today())?,
+        (None, None, Some(stats)) if args.practice => App::new_practice_game(language, stats)?,
+        (None, None, _) => App::new_game(language)?,
+    };
+    app.stats = stats;
+    app.error_message = stats_error;
+    app.endless = args.endless;
+
     let terminal = ratatui::init();
-    let result = run(terminal, language);
+    let result = run(terminal, app);
     ratatui::restore();
     result
 }
 
-fn run(mut terminal: DefaultTerminal, language: CoreLanguage) -> Result<()> {
-    let mut app = App::new_game(language)?;
+/// Days since the Unix epoch, i.e. today's number for `Game::daily`, by UTC date
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs() / (24 * 60 * 60))
+}
 
+fn run(mut terminal: DefaultTerminal, mut app: App) -> Result<()> {
     loop {
         terminal.draw(|frame| render(frame, &app))?;
 
@@ -182,7 +420,7 @@ fn render(frame: &mut Frame, app: &App) {
     let layout = Layout::vertical([
         Constraint::Length(3), // Title
         Constraint::Min(15),   // Game board
-        Constraint::Length(5), // Status/help
+        Constraint::Length(6), // Status/help
     ])
     .split(area);
 
@@ -193,8 +431,11 @@ fn render(frame: &mut Frame, app: &App) {
         .block(Block::default().borders(Borders::ALL));
     frame.render_widget(title, layout[0]);
 
-    // Game board
-    render_game_board(frame, app, layout[1]);
+    // Game board, or the review of its guesses
+    match &app.review {
+        Some(review) => render_review(frame, review, layout[1]),
+        None => render_game_board(frame, app, layout[1]),
+    }
 
     // Status and help
     render_status(frame, app, layout[2]);
@@ -205,36 +446,15 @@ fn render_game_board(frame: &mut Frame, app: &App, area: Rect) {
 
     // Render previous guesses
     for (guess, results) in &app.guesses {
-        let spans: Vec<Span> = guess
-            .iter()
-            .zip(results.iter())
-            .map(|(&ch, &result)| {
-                let color = match result {
-                    LetterResult::Correct => Color::Green,
-                    LetterResult::Misplaced => Color::Yellow,
-                    LetterResult::Absent => Color::DarkGray,
-                };
-                Span::styled(
-                    format!(" {} ", uppercase_display(ch)),
-                    Style::default().fg(Color::Black).bg(color).bold(),
-                )
-            })
-            .collect();
-        lines.push(Line::from(spans));
+        lines.push(wordle_tui::word_row(guess, results));
         lines.push(Line::from(""));
     }
 
     // Render current input (if still playing)
     if app.is_playing() {
-        let mut current_spans = Vec::new();
-        for i in 0..WORD_LENGTH {
-            let ch = app.current_input.get(i).unwrap_or(&' ');
-            current_spans.push(Span::styled(
-                format!(" {} ", uppercase_display(*ch)),
-                Style::default().fg(Color::White).bg(Color::DarkGray),
-            ));
-        }
-        lines.push(Line::from(current_spans));
+        let mut input = app.current_input.clone();
+        input.resize(WORD_LENGTH, ' ');
+        lines.push(wordle_tui::plain_row(&input, Color::DarkGray));
         lines.push(Line::from(""));
     }
 
@@ -244,64 +464,133 @@ fn render_game_board(frame: &mut Frame, app: &App, area: Rect) {
         .max_attempts()
         .saturating_sub(app.guesses.len() + if app.is_playing() { 1 } else { 0 });
     for _ in 0..remaining_rows {
-        let empty_spans: Vec<Span> = (0..WORD_LENGTH)
-            .map(|_| Span::styled("   ", Style::default().fg(Color::DarkGray).bg(Color::Black)))
-            .collect();
-        lines.push(Line::from(empty_spans));
+        lines.push(wordle_tui::empty_row());
         lines.push(Line::from(""));
     }
 
+    let title = match (app.seed, app.daily) {
+        (Some(seed), _) => format!("Board (seed {})", seed),
+        (None, Some(day)) => format!("Board (daily #{})", day),
+        (None, None) if app.practice => "Board (practice)".to_string(),
+        (None, None) => "Board".to_string(),
+    };
     let board = Paragraph::new(lines)
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).title("Board"));
+        .block(Block::default().borders(Borders::ALL).title(title));
 
     frame.render_widget(board, area);
 }
 
+/// Every guess next to the AI's choice at that point, see `review_guesses`
+fn render_review(frame: &mut Frame, review: &[GuessReview], area: Rect) {
+    let mut lines = vec![Line::from("")];
+    for entry in review {
+        let color = if entry.is_optimal() {
+            Color::Green
+        } else {
+            Color::Yellow
+        };
+        lines.push(Line::from(Span::styled(
+            entry.summary(),
+            Style::default().fg(color),
+        )));
+        lines.push(Line::from(""));
+    }
+    if review.is_empty() {
+        lines.push(Line::from("Nothing to review"));
+    }
+
+    let panel = Paragraph::new(lines).alignment(Alignment::Center).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Coaching (press C for the board)"),
+    );
+    frame.render_widget(panel, area);
+}
+
 fn render_status(frame: &mut Frame, app: &App, area: Rect) {
-    let text = match &app.outcome {
-        Some(GameOutcome::Won) => {
+    let mut text = match &app.outcome {
+        Some(GameOutcome::Won { difficulty }) => {
             vec![
                 Line::from(Span::styled(
                     "Congratulations! You won!",
                     Style::default().fg(Color::Green).bold(),
                 )),
-                Line::from(""),
-                Line::from("Press 'R' to restart or 'Q' to quit"),
+                Line::from(difficulty.as_str()),
+                Line::from("Press 'C' for coaching, 'R' to restart or 'Q' to quit"),
             ]
         }
-        Some(GameOutcome::Lost { solution }) => {
-            let solution_str: String = solution.iter().map(|&c| uppercase_display(c)).collect();
+        Some(GameOutcome::Lost {
+            solution,
+            difficulty,
+        }) => {
+            let solution_str: String = solution.iter().map(|&c| display_upper(c)).collect();
             vec![
                 Line::from(Span::styled(
-                    format!("Game Over! The word was: {}", solution_str),
+                    if app.endless {
+                        format!(
+                            "Game Over! The word was: {} (final score: {})",
+                            solution_str, app.score
+                        )
+                    } else {
+                        format!("Game Over! The word was: {}", solution_str)
+                    },
                     Style::default().fg(Color::Red).bold(),
                 )),
-                Line::from(""),
-                Line::from("Press 'R' to restart or 'Q' to quit"),
+                Line::from(difficulty.as_str()),
+                Line::from("Press 'C' for coaching, 'R' to restart or 'Q' to quit"),
             ]
         }
         None => {
-            let mut status_lines = vec![Line::from(format!(
+            let attempt = format!(
                 "Attempt {}/{}",
                 app.game.attempts() + 1,
                 app.game.max_attempts()
-            ))];
+            );
+            let mut status_lines = vec![Line::from(if app.endless {
+                format!("{} | Score: {}", attempt, app.score)
+            } else {
+                attempt
+            })];
 
             if let Some(ref error) = app.error_message {
                 status_lines.push(Line::from(Span::styled(
                     error.clone(),
                     Style::default().fg(Color::Red),
                 )));
+            } else if let Some(hint) = app.hint {
+                let hint_str: String = hint.iter().map(|&c| display_upper(c)).collect();
+                status_lines.push(Line::from(Span::styled(
+                    format!("Hint: try {}", hint_str),
+                    Style::default().fg(Color::Yellow),
+                )));
+            } else if let Some((word, guesses)) = app.last_solved
+                && app.guesses.is_empty()
+            {
+                let word_str: String = word.iter().map(|&c| display_upper(c)).collect();
+                status_lines.push(Line::from(Span::styled(
+                    format!("Solved {} in {}! Next word:", word_str, guesses),
+                    Style::default().fg(Color::Green),
+                )));
             } else {
                 status_lines.push(Line::from("Type a 5-letter word and press Enter"));
             }
 
-            status_lines.push(Line::from("Press Esc to quit"));
+            status_lines.push(Line::from("Press ? for a hint, Esc to quit"));
             status_lines
         }
     };
 
+    // Saving the stats can fail once the game is over
+    if app.outcome.is_some()
+        && let Some(ref error) = app.error_message
+    {
+        text.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
     let status = Paragraph::new(text)
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL).title("Status"));
@@ -0,0 +1,176 @@
+use color_eyre::eyre::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// How the player did on one solution word over all games with it
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WordRecord {
+    pub wins: u32,
+    pub losses: u32,
+    /// Guesses used in the won games
+    pub total_guesses: u32,
+}
+
+/// The player's results per solution word, persisted between runs
+///
+/// Stored as one `word wins losses total_guesses` line per word.
+#[derive(Debug)]
+pub struct WordStats {
+    path: PathBuf,
+    words: HashMap<[char; 5], WordRecord>,
+}
+
+impl WordStats {
+    /// Where stats are kept unless another file is given: `.wordle_cli_stats` in the home directory
+    pub fn default_path() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| Path::new(&home).join(".wordle_cli_stats"))
+    }
+
+    /// Read the stats from a file, starting empty if it doesn't exist yet
+    ///
+    /// Lines that can't be parsed are skipped.
+    pub fn load(path: PathBuf) -> Result<Self> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let words = contents.lines().filter_map(parse_line).collect();
+        Ok(Self { path, words })
+    }
+
+    /// Write the stats back to the file they were loaded from
+    pub fn save(&self) -> Result<()> {
+        let mut words: Vec<_> = self.words.iter().collect();
+        words.sort_by_key(|&(word, _)| *word);
+
+        let contents: String = words
+            .into_iter()
+            .map(|(word, record)| {
+                format!(
+                    "{} {} {} {}\n",
+                    word.iter().collect::<String>(),
+                    record.wins,
+                    record.losses,
+                    record.total_guesses
+                )
+            })
+            .collect();
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+
+    /// Record a finished game, with the number of guesses if it was won
+    pub fn record(&mut self, word: [char; 5], guesses: Option<usize>) {
+        let record = self.words.entry(word).or_default();
+        match guesses {
+            Some(guesses) => {
+                record.wins += 1;
+                record.total_guesses += guesses as u32;
+            }
+            None => record.losses += 1,
+        }
+    }
+
+    /// How much the player should practice a word, 0 for words they did fine on
+    ///
+    /// Every loss counts 3, and every guess beyond the third in a win counts 1.
+    pub fn practice_weight(&self, word: &[char; 5]) -> f64 {
+        self.words.get(word).map_or(0.0, |record| {
+            let extra_guesses = record.total_guesses.saturating_sub(3 * record.wins);
+            (3 * record.losses + extra_guesses) as f64
+        })
+    }
+}
+
+fn parse_line(line: &str) -> Option<([char; 5], WordRecord)> {
+    let mut fields = line.split_whitespace();
+    let word: [char; 5] = fields.next()?.chars().collect::<Vec<_>>().try_into().ok()?;
+    let record = WordRecord {
+        wins: fields.next()?.parse().ok()?,
+        losses: fields.next()?.parse().ok()?,
+        total_guesses: fields.next()?.parse().ok()?,
+    };
+    Some((word, record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APPLE: [char; 5] = ['a', 'p', 'p', 'l', 'e'];
+    const CRANE: [char; 5] = ['c', 'r', 'a', 'n', 'e'];
+
+    fn empty_stats(path: PathBuf) -> WordStats {
+        WordStats {
+            path,
+            words: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let record = WordRecord {
+            wins: 2,
+            losses: 1,
+            total_guesses: 7,
+        };
+        assert_eq!(parse_line("apple 2 1 7"), Some((APPLE, record)));
+
+        // Words of the wrong length and missing or invalid counts are skipped
+        assert_eq!(parse_line("apples 2 1 7"), None);
+        assert_eq!(parse_line("apple 2 1"), None);
+        assert_eq!(parse_line("apple two 1 7"), None);
+        assert_eq!(parse_line(""), None);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut stats = empty_stats(PathBuf::new());
+        stats.record(APPLE, Some(4));
+        stats.record(APPLE, Some(2));
+        stats.record(APPLE, None);
+
+        let record = WordRecord {
+            wins: 2,
+            losses: 1,
+            total_guesses: 6,
+        };
+        assert_eq!(stats.words.get(&APPLE), Some(&record));
+        assert_eq!(stats.words.get(&CRANE), None);
+    }
+
+    #[test]
+    fn test_practice_weight() {
+        let mut stats = empty_stats(PathBuf::new());
+        assert_eq!(stats.practice_weight(&APPLE), 0.0);
+
+        // Wins within three guesses don't need practice
+        stats.record(CRANE, Some(2));
+        stats.record(CRANE, Some(3));
+        assert_eq!(stats.practice_weight(&CRANE), 0.0);
+
+        // A loss counts 3, and a win in five guesses 2
+        stats.record(APPLE, None);
+        stats.record(APPLE, Some(5));
+        assert_eq!(stats.practice_weight(&APPLE), 5.0);
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("wordle_cli_stats_{}", std::process::id()));
+        let mut stats = WordStats::load(path.clone()).unwrap();
+        assert!(stats.words.is_empty());
+
+        stats.record(APPLE, Some(4));
+        stats.record(CRANE, None);
+        stats.save().unwrap();
+
+        let loaded = WordStats::load(path.clone()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.words, stats.words);
+    }
+}
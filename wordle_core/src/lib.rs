@@ -1,18 +1,31 @@
 use rand::prelude::*;
-use std::collections::HashSet;
-use std::sync::LazyLock;
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 use wordle_proc::include_wordlist;
 
-const WORDLIST_EN_ARRAY: &[[char; 5]] = &include_wordlist!("wordlist-en.txt");
-const WORDLIST_DE_ARRAY: &[[char; 5]] = &include_wordlist!("wordlist-de.txt");
+/// Number of letters in the words of classic Wordle and of the built-in word lists
+pub const WORD_LENGTH: usize = 5;
+/// Number of guesses a standard game of Wordle allows
+pub const DEFAULT_MAX_ATTEMPTS: usize = 6;
 
-static WORDLIST_EN: LazyLock<HashSet<[char; 5]>> =
-    LazyLock::new(|| WORDLIST_EN_ARRAY.iter().copied().collect());
-static WORDLIST_DE: LazyLock<HashSet<[char; 5]>> =
-    LazyLock::new(|| WORDLIST_DE_ARRAY.iter().copied().collect());
+const WORDLIST_EN_ARRAY: &[[char; 5]] = &include_wordlist!("../wordlist-en.txt");
+const WORDLIST_DE_ARRAY: &[[char; 5]] = &include_wordlist!("../wordlist-de.txt");
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+const ALPHABET_EN: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+const ALPHABET_DE: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ü', 'ß',
+];
+/// Accented letters of loanwords in the German word list, like the é in 'cafés'
+const LOANWORD_LETTERS_DE: &[char] = &[
+    'à', 'á', 'å', 'ç', 'è', 'é', 'ê', 'ë', 'í', 'ñ', 'ó', 'ô', 'ø',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub enum Language {
     #[default]
     English,
@@ -20,6 +33,12 @@ pub enum Language {
 }
 
 impl Language {
+    /// Every supported language, in declaration order
+    pub fn all() -> &'static [Language] {
+        &[Language::English, Language::German]
+    }
+
+    /// All words of this language, sorted and without duplicates
     pub fn wordlist_array(&self) -> &'static [[char; 5]] {
         match self {
             Language::English => WORDLIST_EN_ARRAY,
@@ -27,12 +46,163 @@ impl Language {
         }
     }
 
-    fn wordlist_set(&self) -> &'static HashSet<[char; 5]> {
+    /// Whether the word is in this language's word list
+    ///
+    /// A binary search, since `include_wordlist!` emits the words sorted.
+    pub fn contains(&self, word: &[char; 5]) -> bool {
+        self.wordlist_array().binary_search(word).is_ok()
+    }
+
+    /// Strong opening words to suggest to new players, each from the word list
+    ///
+    /// They use five different letters that are common in the language.
+    pub fn recommended_openers(&self) -> &'static [[char; 5]] {
+        match self {
+            Language::English => &[
+                ['s', 'l', 'a', 't', 'e'],
+                ['c', 'r', 'a', 'n', 'e'],
+                ['a', 'd', 'i', 'e', 'u'],
+            ],
+            Language::German => &[
+                ['t', 'a', 'r', 'n', 'e'],
+                ['a', 'r', 's', 'e', 'n'],
+                ['r', 'i', 'n', 'd', 'e'],
+            ],
+        }
+    }
+
+    /// The lowercase letters words of this language are made of
+    ///
+    /// German adds the umlauts and ß, which are letters of their own in Wordle. A few
+    /// loanwords in the German word list use further letters, like the é in 'cafés'.
+    pub fn alphabet(&self) -> &'static [char] {
+        match self {
+            Language::English => ALPHABET_EN,
+            Language::German => ALPHABET_DE,
+        }
+    }
+
+    /// How hard a word is to find, in bits still missing after a strong opener
+    ///
+    /// Counts the words of this language's word list, including `word`, that give the same
+    /// feedback as `word` to the first of [`Language::recommended_openers`], and returns the
+    /// log2 of that count. 0 means the opener alone pins the word down; each extra bit
+    /// doubles the number of words that remain to be told apart, like 'hatch' among 'batch',
+    /// 'catch', 'match' and 'watch'.
+    pub fn word_difficulty(&self, word: &[char; 5]) -> f64 {
+        let opener = &self.recommended_openers()[0];
+        let pattern = take_guess(word, opener);
+        let competitors = self
+            .wordlist_array()
+            .iter()
+            .filter(|other| take_guess(other, opener) == pattern)
+            .count()
+            // A word from outside the list competes with the list's words too
+            .max(1);
+        (competitors as f64).log2()
+    }
+
+    /// Describe how hard a word is to find for players, see [`Language::word_difficulty`]
+    pub fn describe_difficulty(&self, word: &[char; 5]) -> String {
+        let difficulty = self.word_difficulty(word);
+        let opener: String = self.recommended_openers()[0]
+            .iter()
+            .map(|&c| display_upper(c))
+            .collect();
+        format!(
+            "Difficulty: {:.1} bits ({} words look alike after {})",
+            difficulty,
+            difficulty.exp2().round(),
+            opener
+        )
+    }
+
+    /// Characters beyond the [`Language::alphabet`] that words of this language may contain,
+    /// like the accents of German loanwords or the apostrophe in French elisions
+    fn extra_chars(&self) -> &'static [char] {
         match self {
-            Language::English => &WORDLIST_EN,
-            Language::German => &WORDLIST_DE,
+            Language::English => &[],
+            Language::German => LOANWORD_LETTERS_DE,
+        }
+    }
+
+    /// The characters words of this language are made of
+    fn char_set(&self) -> CharSet {
+        CharSet {
+            alphabet: self.alphabet(),
+            extra_chars: self.extra_chars(),
         }
     }
+
+    /// Whether `c`, in either case, may appear in a word of this language
+    ///
+    /// Letters of other languages are rejected, like ß in English.
+    pub fn is_valid_char(&self, c: char) -> bool {
+        self.char_set().contains(c)
+    }
+
+    /// Parse a word list with one word per line, like `include_wordlist!` does at compile time
+    ///
+    /// Lines that are not exactly 5 characters long or contain characters that are not
+    /// valid in this language are skipped.
+    pub fn parse_wordlist(&self, contents: &str) -> Vec<[char; 5]> {
+        parse_wordlist(contents, self.char_set())
+    }
+
+    /// Pick a word from this language's word list uniformly at random
+    ///
+    /// On WASM, the final binary must enable the `wasm_js` feature of `getrandom` for the
+    /// thread-local RNG to work.
+    pub fn random_word(&self) -> [char; 5] {
+        self.random_word_with(&mut rand::rng())
+    }
+
+    /// Like [`Language::random_word`], but `None` instead of panicking when the platform
+    /// offers no randomness, e.g. on WASM without the `wasm_js` feature of `getrandom`
+    pub fn try_random_word(&self) -> Option<[char; 5]> {
+        let mut rng = StdRng::try_from_os_rng().ok()?;
+        Some(self.random_word_with(&mut rng))
+    }
+
+    /// Like [`Language::random_word`], but always the same word for the same seed
+    pub fn random_word_seeded(&self, seed: u64) -> [char; 5] {
+        self.random_word_with(&mut StdRng::seed_from_u64(seed))
+    }
+
+    fn random_word_with(&self, rng: &mut impl Rng) -> [char; 5] {
+        *self
+            .wordlist_array()
+            .choose(rng)
+            .expect("word lists are never empty")
+    }
+}
+
+/// The characters allowed in the words of a language, see [`Language::is_valid_char`]
+#[derive(Debug, Clone, Copy)]
+struct CharSet {
+    alphabet: &'static [char],
+    extra_chars: &'static [char],
+}
+
+impl CharSet {
+    fn contains(&self, c: char) -> bool {
+        let c = lowercase_letter(c);
+        self.alphabet.contains(&c) || self.extra_chars.contains(&c)
+    }
+}
+
+fn parse_wordlist(contents: &str, char_set: CharSet) -> Vec<[char; 5]> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let chars = lowercase_word(line);
+            if chars.iter().all(|&c| char_set.contains(c)) {
+                chars.try_into().ok()
+            } else {
+                None
+            }
+        })
+        .collect()
 }
 
 #[derive(Debug)]
@@ -43,14 +213,23 @@ pub enum WordListError {
 #[derive(Debug)]
 pub enum GameError {
     WordNotInList,
+    /// The game has already been won or lost
+    GameOver,
+    /// The input is not [`WORD_LENGTH`] letters long
+    WrongLength,
+    /// The input contains a character that is not a letter of the game's language
+    InvalidChar(char),
+    /// In hard mode, the guess doesn't reuse every hint revealed so far
+    ViolatesHardMode,
 }
 
-pub enum GuessResult {
-    Continue([LetterResult; 5]),
-    Won([LetterResult; 5]),
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GuessResult<const N: usize = WORD_LENGTH> {
+    Continue([LetterResult; N]),
+    Won([LetterResult; N]),
     Lost {
-        last_guess: [LetterResult; 5],
-        solution: [char; 5],
+        last_guess: [LetterResult; N],
+        solution: [char; N],
     },
 }
 
@@ -61,9 +240,55 @@ pub enum LetterResult {
     Absent,
 }
 
-pub fn take_guess(solution: &[char; 5], guess: &[char; 5]) -> [LetterResult; 5] {
-    let mut result = [LetterResult::Absent; 5];
-    let mut solution_used = [false; 5];
+/// Format feedback like `GYBBG` (Green/Yellow/Black), the way [`parse_feedback`] reads it
+pub fn format_feedback<const N: usize>(results: &[LetterResult; N]) -> String {
+    results
+        .iter()
+        .map(|result| match result {
+            LetterResult::Correct => 'G',
+            LetterResult::Misplaced => 'Y',
+            LetterResult::Absent => 'B',
+        })
+        .collect()
+}
+
+/// Parse feedback like `GYBBG` (Green/Yellow/Black), in any case
+///
+/// The terminal assistant's key letters are accepted too: C (Correct), M (Misplaced),
+/// A (Absent).
+pub fn parse_feedback<const N: usize>(feedback: &str) -> Result<[LetterResult; N], GameError> {
+    let results = feedback
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'G' | 'C' => Ok(LetterResult::Correct),
+            'Y' | 'M' => Ok(LetterResult::Misplaced),
+            'B' | 'A' => Ok(LetterResult::Absent),
+            _ => Err(GameError::InvalidChar(c)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    results.try_into().map_err(|_| GameError::WrongLength)
+}
+
+/// The next feedback when cycling through the options of a tile, starting from an empty one
+/// - forward: Absent -> Misplaced -> Correct -> Absent, and Misplaced when empty
+/// - backward: Absent -> Correct -> Misplaced -> Absent, and Absent when empty
+pub fn cycle_feedback(current: Option<LetterResult>, forward: bool) -> LetterResult {
+    match (current, forward) {
+        (None, true) => LetterResult::Misplaced,
+        (Some(LetterResult::Absent), true) => LetterResult::Misplaced,
+        (Some(LetterResult::Misplaced), true) => LetterResult::Correct,
+        (Some(LetterResult::Correct), true) => LetterResult::Absent,
+
+        (None, false) => LetterResult::Absent,
+        (Some(LetterResult::Absent), false) => LetterResult::Correct,
+        (Some(LetterResult::Misplaced), false) => LetterResult::Absent,
+        (Some(LetterResult::Correct), false) => LetterResult::Misplaced,
+    }
+}
+
+pub fn take_guess<const N: usize>(solution: &[char; N], guess: &[char; N]) -> [LetterResult; N] {
+    let mut result = [LetterResult::Absent; N];
+    let mut solution_used = [false; N];
 
     // First pass: mark correct positions
     for (i, &guess_char) in guess.iter().enumerate() {
@@ -91,53 +316,462 @@ pub fn take_guess(solution: &[char; 5], guess: &[char; 5]) -> [LetterResult; 5]
     result
 }
 
-#[derive(Clone)]
-pub struct Game {
-    solution: [char; 5],
+/// The feedback for a guess, together with how often each guessed letter can appear in
+/// the solution given that feedback alone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuessAnalysis<const N: usize = WORD_LENGTH> {
+    /// The color of each letter, as returned by [`take_guess`]
+    pub result: [LetterResult; N],
+    /// For every letter of the guess, the minimum and, if known, maximum number of times it
+    /// appears in the solution
+    ///
+    /// The minimum is the number of the letter's Correct and Misplaced tiles. If some tile of
+    /// the letter is Absent, the solution has no more of it, so the maximum equals the minimum.
+    pub letter_counts: HashMap<char, (u8, Option<u8>)>,
+}
+
+impl<const N: usize> GuessAnalysis<N> {
+    /// Deduce the letter counts from the feedback for a guess
+    pub fn new(guess: &[char; N], result: [LetterResult; N]) -> Self {
+        let mut letter_counts: HashMap<char, (u8, Option<u8>)> = HashMap::new();
+        for (&letter, &letter_result) in guess.iter().zip(result.iter()) {
+            let (min, max) = letter_counts.entry(letter).or_insert((0, None));
+            match letter_result {
+                LetterResult::Correct | LetterResult::Misplaced => *min += 1,
+                LetterResult::Absent => *max = Some(0),
+            }
+        }
+        // An Absent tile caps the count at the letter's colored tiles, wherever they are
+        for (min, max) in letter_counts.values_mut() {
+            if max.is_some() {
+                *max = Some(*min);
+            }
+        }
+
+        Self {
+            result,
+            letter_counts,
+        }
+    }
+}
+
+/// Like [`take_guess`], but also deduce how often each guessed letter appears in the
+/// solution, see [`GuessAnalysis`]
+pub fn take_guess_detailed<const N: usize>(
+    solution: &[char; N],
+    guess: &[char; N],
+) -> GuessAnalysis<N> {
+    GuessAnalysis::new(guess, take_guess(solution, guess))
+}
+
+/// Whether no letter appears twice in the word
+pub fn has_distinct_letters(word: &[char; 5]) -> bool {
+    word.iter()
+        .enumerate()
+        .all(|(i, letter)| !word[i + 1..].contains(letter))
+}
+
+/// Display a letter in uppercase, but keep letters whose uppercase form is more than
+/// one character (like ß, which would become SS) as they are
+pub fn display_upper(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+/// Lowercase a letter, but keep letters whose lowercase form is more than one
+/// character (like İ, which would become i̇) as they are
+///
+/// Unlike `str::to_lowercase`, this never changes the number of letters in a word.
+pub fn lowercase_letter(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Lowercase every letter of a word with [`lowercase_letter`], e.g. to parse user input
+pub fn lowercase_word(word: &str) -> Vec<char> {
+    word.chars().map(lowercase_letter).collect()
+}
+
+/// Turn typed input into a guess: trimmed, lowercased with [`lowercase_letter`] and
+/// checked to be [`WORD_LENGTH`] letters of `language`
+///
+/// Whether the word is in the word list is left to [`Game::take_guess`].
+pub fn normalize_guess(input: &str, language: Language) -> Result<[char; 5], GameError> {
+    let word = lowercase_word(input.trim());
+    if let Some(&c) = word.iter().find(|&&c| !language.is_valid_char(c)) {
+        return Err(GameError::InvalidChar(c));
+    }
+    word.try_into().map_err(|_| GameError::WrongLength)
+}
+
+/// Key used to obfuscate shared words, so they aren't readable at a glance
+const SHARE_KEY: &[u8] = b"wordle";
+
+/// Encode a word into an obfuscated, URL-safe string for sharing
+pub fn encode_word<const N: usize>(word: &[char; N]) -> String {
+    let text: String = word.iter().collect();
+    text.bytes()
+        .zip(SHARE_KEY.iter().cycle())
+        .map(|(byte, key)| format!("{:02x}", byte ^ key))
+        .collect()
+}
+
+/// Decode a string created by [`encode_word`], or `None` if it isn't a valid encoded word
+pub fn decode_word(encoded: &str) -> Option<[char; 5]> {
+    if !encoded.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let bytes = (0..encoded.len())
+        .step_by(2)
+        .zip(SHARE_KEY.iter().cycle())
+        .map(|(i, key)| {
+            let byte = u8::from_str_radix(encoded.get(i..i + 2)?, 16).ok()?;
+            Some(byte ^ key)
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    let chars: Vec<char> = String::from_utf8(bytes).ok()?.chars().collect();
+    chars.try_into().ok()
+}
+
+/// The hints every guess must reuse in hard mode, accumulated over the guesses so far
+#[derive(Debug, Clone)]
+struct HardModeHints<const N: usize> {
+    /// Letters found in their position
+    correct: [Option<char>; N],
+    /// How often each found letter must appear at least, counting Correct and Misplaced tiles
+    min_counts: HashMap<char, u8>,
+}
+
+impl<const N: usize> HardModeHints<N> {
+    fn new() -> Self {
+        Self {
+            correct: [None; N],
+            min_counts: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, guess: &[char; N], result: [LetterResult; N]) {
+        for (position, (&letter, &letter_result)) in guess.iter().zip(result.iter()).enumerate() {
+            if letter_result == LetterResult::Correct {
+                self.correct[position] = Some(letter);
+            }
+        }
+        for (letter, (min, _)) in GuessAnalysis::new(guess, result).letter_counts {
+            let required = self.min_counts.entry(letter).or_insert(0);
+            *required = (*required).max(min);
+        }
+    }
+
+    /// Whether the guess keeps every Correct letter in place and contains every found letter
+    /// at least as often as it was found
+    fn allow(&self, guess: &[char; N]) -> bool {
+        let keeps_correct = self
+            .correct
+            .iter()
+            .zip(guess)
+            .all(|(correct, letter)| correct.is_none_or(|correct| correct == *letter));
+        let uses_found = self
+            .min_counts
+            .iter()
+            .all(|(&letter, &min)| guess.iter().filter(|&&c| c == letter).count() >= min as usize);
+        keeps_correct && uses_found
+    }
+}
+
+/// Callback invoked with the result of every accepted guess, see [`Game::set_on_result`]
+///
+/// Behind a `Mutex` only so that games stay `Sync`; `take_guess` has the game mutably
+/// borrowed anyway, so the lock is never contended.
+type ResultCallback<const N: usize> = Mutex<Box<dyn FnMut(&GuessResult<N>) + Send>>;
+
+/// A game of Wordle with `N`-letter words, 5 unless a game is started from a word list of
+/// another length with [`Game::new_with_wordlist`]
+pub struct Game<const N: usize = WORD_LENGTH> {
+    solution: [char; N],
     max_attempts: usize,
     attempts: usize,
     language: Language,
+    /// The words that may be guessed, sorted for binary search
+    wordlist: &'static [[char; N]],
+    guesses: Vec<([char; N], [LetterResult; N])>,
+    finished: bool,
+    on_result: Option<ResultCallback<N>>,
+    /// Whether every guess must reuse the hints revealed so far, see [`Game::with_hard_mode`]
+    hard_mode: bool,
+    hints: HardModeHints<N>,
+}
+
+/// A game of classic, 5-letter Wordle
+pub type ClassicGame = Game<5>;
+
+/// Clones start without the callback of [`Game::set_on_result`], so a clone taking a guess
+/// never reports it to the original's observer
+impl<const N: usize> Clone for Game<N> {
+    fn clone(&self) -> Self {
+        Game {
+            solution: self.solution,
+            max_attempts: self.max_attempts,
+            attempts: self.attempts,
+            language: self.language,
+            wordlist: self.wordlist,
+            guesses: self.guesses.clone(),
+            finished: self.finished,
+            on_result: None,
+            hard_mode: self.hard_mode,
+            hints: self.hints.clone(),
+        }
+    }
+}
+
+/// Scramble a number (the SplitMix64 finalizer), so consecutive numbers map to unrelated ones
+///
+/// Used to pick the daily puzzle from the day number, and to derive independent seeds from a
+/// seed and a counter.
+pub fn split_mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
 }
 
-impl Game {
+impl Game<5> {
+    /// Start a game with a random solution, i.e. [`Game::new_with_seed`] with a random seed
     pub fn new(max_attempts: usize, language: Language) -> Result<Game, WordListError> {
-        let mut rng = rand::rng();
-        match language.wordlist_array().choose(&mut rng) {
-            Some(&word) => Ok(Game {
-                solution: word,
-                max_attempts,
-                attempts: 0,
-                language,
-            }),
-            None => Err(WordListError::WordListEmpty),
+        Game::new_with_seed(max_attempts, language, rand::random())
+    }
+
+    /// Like [`Game::new`], but always the same solution for the same seed and language
+    ///
+    /// The solution only depends on the seed, so a seed can be shared to play the same game
+    /// elsewhere, e.g. in the browser and in the terminal, without revealing the word.
+    pub fn new_with_seed(
+        max_attempts: usize,
+        language: Language,
+        seed: u64,
+    ) -> Result<Game, WordListError> {
+        if language.wordlist_array().is_empty() {
+            return Err(WordListError::WordListEmpty);
+        }
+
+        Ok(Game::with_solution(
+            max_attempts,
+            language,
+            language.wordlist_array(),
+            language.random_word_seeded(seed),
+        ))
+    }
+
+    /// The daily puzzle: the same solution for everyone playing `language` on the same day
+    ///
+    /// `day` is the number of days since the Unix epoch (1970-01-01 is day 0); the caller
+    /// decides which day it is, e.g. in UTC or local time. Unlike [`Game::new_with_seed`], the
+    /// day is mapped to the word with a fixed hash instead of `rand`, so the puzzle stays the
+    /// same across machines and dependency updates as long as the word list doesn't change.
+    pub fn daily(language: Language, day: u64) -> Result<Game, WordListError> {
+        let wordlist = language.wordlist_array();
+        if wordlist.is_empty() {
+            return Err(WordListError::WordListEmpty);
+        }
+
+        let index = (split_mix(day) % wordlist.len() as u64) as usize;
+        Ok(Game::with_solution(
+            DEFAULT_MAX_ATTEMPTS,
+            language,
+            wordlist,
+            wordlist[index],
+        ))
+    }
+
+    /// Like [`Game::new`], but the solution has five different letters, for an easier game
+    ///
+    /// Fails with [`WordListError::WordListEmpty`] if no word of the language qualifies.
+    pub fn new_no_repeats(max_attempts: usize, language: Language) -> Result<Game, WordListError> {
+        let words: Vec<&[char; 5]> = language
+            .wordlist_array()
+            .iter()
+            .filter(|word| has_distinct_letters(word))
+            .collect();
+        let solution = **words
+            .choose(&mut rand::rng())
+            .ok_or(WordListError::WordListEmpty)?;
+
+        Ok(Game::with_solution(
+            max_attempts,
+            language,
+            language.wordlist_array(),
+            solution,
+        ))
+    }
+
+    /// Like [`Game::new`], but words are picked with probability proportional to their weight
+    ///
+    /// Words with a weight of zero are never picked. Fails with [`WordListError::WordListEmpty`]
+    /// if no word has a positive weight.
+    pub fn new_weighted(
+        max_attempts: usize,
+        language: Language,
+        weight: impl Fn(&[char; 5]) -> f64,
+    ) -> Result<Game, WordListError> {
+        let solution = *language
+            .wordlist_array()
+            .choose_weighted(&mut rand::rng(), |word| weight(word).max(0.0))
+            .map_err(|_| WordListError::WordListEmpty)?;
+
+        Ok(Game::with_solution(
+            max_attempts,
+            language,
+            language.wordlist_array(),
+            solution,
+        ))
+    }
+
+    /// Start a game with a chosen solution, e.g. one entered by another player or shared by link
+    ///
+    /// Fails with [`GameError::WordNotInList`] if `word` is not in the language's word list.
+    pub fn new_with_word(
+        max_attempts: usize,
+        language: Language,
+        word: [char; 5],
+    ) -> Result<Game, GameError> {
+        if !language.contains(&word) {
+            return Err(GameError::WordNotInList);
+        }
+
+        Ok(Game::with_solution(
+            max_attempts,
+            language,
+            language.wordlist_array(),
+            word,
+        ))
+    }
+}
+
+impl<const N: usize> Game<N> {
+    /// Start a game of `N`-letter words from a word list of its own, e.g. for 4- or 6-letter
+    /// variants with a list from `include_wordlist!("words.txt", 4)`
+    ///
+    /// The word list must be sorted, as `include_wordlist!` emits it, since guesses are looked
+    /// up by binary search. `language` only tells which alphabet the words are written in.
+    pub fn new_with_wordlist(
+        max_attempts: usize,
+        language: Language,
+        wordlist: &'static [[char; N]],
+        solution: [char; N],
+    ) -> Result<Self, GameError> {
+        if wordlist.binary_search(&solution).is_err() {
+            return Err(GameError::WordNotInList);
+        }
+
+        Ok(Self::with_solution(
+            max_attempts,
+            language,
+            wordlist,
+            solution,
+        ))
+    }
+
+    fn with_solution(
+        max_attempts: usize,
+        language: Language,
+        wordlist: &'static [[char; N]],
+        solution: [char; N],
+    ) -> Self {
+        Game {
+            solution,
+            max_attempts,
+            attempts: 0,
+            language,
+            wordlist,
+            guesses: Vec::new(),
+            finished: false,
+            on_result: None,
+            hard_mode: false,
+            hints: HardModeHints::new(),
         }
     }
 
-    pub fn take_guess(&mut self, guess: &[char; 5]) -> Result<GuessResult, GameError> {
-        if !self.language.wordlist_set().contains(guess) {
+    /// Play in hard mode: every guess must keep the letters found in their position there and
+    /// contain every other found letter, or [`Game::take_guess`] rejects it with
+    /// [`GameError::ViolatesHardMode`]
+    ///
+    /// A letter found twice, e.g. once Correct and once Misplaced, must appear twice.
+    pub fn with_hard_mode(mut self) -> Self {
+        self.hard_mode = true;
+        self
+    }
+
+    pub fn take_guess(&mut self, guess: &[char; N]) -> Result<GuessResult<N>, GameError> {
+        if self.finished {
+            return Err(GameError::GameOver);
+        }
+        if self.wordlist.binary_search(guess).is_err() {
             return Err(GameError::WordNotInList);
         }
+        if self.hard_mode && !self.hints.allow(guess) {
+            return Err(GameError::ViolatesHardMode);
+        }
 
         let result = take_guess(&self.solution, guess);
         self.attempts += 1;
+        self.guesses.push((*guess, result));
+        self.hints.add(guess, result);
 
         let is_won = result.iter().all(|&r| r == LetterResult::Correct);
         let is_last_attempt = !self.has_attempts_left();
+        self.finished = is_won || is_last_attempt;
 
-        Ok(match (is_won, is_last_attempt) {
+        let guess_result = match (is_won, is_last_attempt) {
             (true, _) => GuessResult::Won(result),
             (false, true) => GuessResult::Lost {
                 last_guess: result,
                 solution: self.solution,
             },
             (false, false) => GuessResult::Continue(result),
-        })
+        };
+        if let Some(on_result) = &mut self.on_result {
+            // Keep calling the callback even if it panicked during an earlier guess
+            let on_result = on_result.get_mut().unwrap_or_else(|e| e.into_inner());
+            on_result(&guess_result);
+        }
+        Ok(guess_result)
+    }
+
+    /// Call `callback` with the result of every accepted guess, e.g. to play a sound,
+    /// start an animation or update statistics in one place
+    ///
+    /// Rejected guesses (`Err`) don't reach the callback. Without a callback, taking a
+    /// guess costs nothing extra.
+    ///
+    /// The callback runs inside `take_guess`, while the game is mutably borrowed, so it
+    /// can't access the game itself. It must be `Send`, so games can still move between
+    /// threads and live in UI signals; share any state it needs through `Arc<Mutex<_>>` or
+    /// channels instead of `Rc<RefCell<_>>`. Clones of the game don't keep the callback.
+    pub fn set_on_result(&mut self, callback: Box<dyn FnMut(&GuessResult<N>) + Send>) {
+        self.on_result = Some(Mutex::new(callback));
+    }
+
+    /// Remove the callback set with [`Game::set_on_result`]
+    pub fn clear_on_result(&mut self) {
+        self.on_result = None;
     }
 
     pub fn has_attempts_left(&self) -> bool {
         self.attempts < self.max_attempts
     }
 
+    /// Whether the game has been won or lost, after which no more guesses are taken
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
     pub fn attempts(&self) -> usize {
         self.attempts
     }
@@ -149,11 +783,100 @@ impl Game {
     pub fn language(&self) -> Language {
         self.language
     }
+
+    /// Whether the game is played in hard mode, see [`Game::with_hard_mode`]
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Position of the solution in the game's word list, e.g. in
+    /// [`Language::wordlist_array`] for a game of a language's words
+    ///
+    /// Starting a game with the word at this position, e.g. with [`Game::new_with_word`],
+    /// plays the same game again.
+    pub fn solution_index(&self) -> usize {
+        self.wordlist
+            .binary_search(&self.solution)
+            .expect("the solution is in the word list")
+    }
+
+    /// The solution encoded with [`encode_word`], to share the game without revealing the word
+    pub fn share_code(&self) -> String {
+        encode_word(&self.solution)
+    }
+
+    /// Every accepted guess together with its feedback, in order
+    pub fn guesses(&self) -> &[([char; N], [LetterResult; N])] {
+        &self.guesses
+    }
+
+    /// The best-known state of every letter guessed so far, e.g. for an on-screen keyboard
+    ///
+    /// See [`letter_states`].
+    pub fn letter_states(&self) -> HashMap<char, LetterResult> {
+        letter_states(&self.guesses)
+    }
+}
+
+/// The best-known state of every letter in the given guesses and their results
+///
+/// Correct beats Misplaced beats Absent, so a letter that was found once is never
+/// downgraded by a later (or repeated) occurrence that was marked Absent.
+pub fn letter_states<const N: usize>(
+    guesses: &[([char; N], [LetterResult; N])],
+) -> HashMap<char, LetterResult> {
+    fn rank(result: LetterResult) -> u8 {
+        match result {
+            LetterResult::Absent => 0,
+            LetterResult::Misplaced => 1,
+            LetterResult::Correct => 2,
+        }
+    }
+
+    let mut states = HashMap::new();
+    for (guess, result) in guesses {
+        for (&letter, &letter_result) in guess.iter().zip(result) {
+            states
+                .entry(letter)
+                .and_modify(|state: &mut LetterResult| {
+                    if rank(letter_result) > rank(*state) {
+                        *state = letter_result;
+                    }
+                })
+                .or_insert(letter_result);
+        }
+    }
+    states
+}
+
+/// Helpers for the tests of this crate and of the crates building on it
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util {
+    /// Every word over the given alphabet, covering all repeated-letter patterns
+    pub fn all_words(alphabet: &[char]) -> Vec<[char; 5]> {
+        let mut words = vec![[alphabet[0]; 5]];
+        for position in 0..5 {
+            words = words
+                .into_iter()
+                .flat_map(|word| {
+                    alphabet.iter().map(move |&letter| {
+                        let mut word = word;
+                        word[position] = letter;
+                        word
+                    })
+                })
+                .collect();
+        }
+        words
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use test_util::all_words;
 
     #[test]
     fn test_take_guess() {
@@ -205,4 +928,727 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_take_guess_letter_counts() {
+        // Exhaustive over a small alphabet, so every combination of repeated letters is covered
+        let alphabet = ['a', 'b', 'c'];
+        let words = all_words(&alphabet);
+        for solution in &words {
+            for guess in &words {
+                let result = take_guess(solution, guess);
+
+                for position in 0..5 {
+                    // Correct exactly where the letters match
+                    assert_eq!(
+                        result[position] == LetterResult::Correct,
+                        guess[position] == solution[position],
+                        "{:?} vs {:?}",
+                        solution,
+                        guess
+                    );
+                }
+
+                for letter in alphabet {
+                    let in_guess = guess.iter().filter(|&&c| c == letter).count();
+                    let in_solution = solution.iter().filter(|&&c| c == letter).count();
+                    let colored = guess
+                        .iter()
+                        .zip(result)
+                        .filter(|&(&c, r)| c == letter && r != LetterResult::Absent)
+                        .count();
+                    assert_eq!(
+                        colored,
+                        in_guess.min(in_solution),
+                        "{:?} vs {:?}",
+                        solution,
+                        guess
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_guess_detailed() {
+        let solution = ['t', 'h', 'e', 'r', 'e'];
+        let analysis = take_guess_detailed(&solution, &['e', 'e', 'r', 'i', 'e']);
+
+        assert_eq!(
+            analysis.result,
+            [
+                LetterResult::Misplaced,
+                LetterResult::Absent,
+                LetterResult::Misplaced,
+                LetterResult::Absent,
+                LetterResult::Correct
+            ]
+        );
+        // Two colored E tiles and one absent: exactly two
+        assert_eq!(analysis.letter_counts[&'e'], (2, Some(2)));
+        // One colored R tile and no absent one: at least one
+        assert_eq!(analysis.letter_counts[&'r'], (1, None));
+        assert_eq!(analysis.letter_counts[&'i'], (0, Some(0)));
+        assert_eq!(analysis.letter_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_take_guess_detailed_bounds_solution() {
+        for solution in all_words(&['a', 'b', 'c']) {
+            for guess in all_words(&['a', 'b', 'c']) {
+                let analysis = take_guess_detailed(&solution, &guess);
+                for (letter, &(min, max)) in &analysis.letter_counts {
+                    let count = solution.iter().filter(|c| *c == letter).count() as u8;
+                    assert!(min <= count, "{:?} vs {:?}", solution, guess);
+                    assert!(max.is_none_or(|max| count <= max));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_guess_solution_is_all_correct() {
+        for word in all_words(&['a', 'b', 'c'])
+            .into_iter()
+            .chain(Language::English.wordlist_array().iter().copied())
+        {
+            assert_eq!(take_guess(&word, &word), [LetterResult::Correct; 5]);
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_word() {
+        for word in [['h', 'e', 'l', 'l', 'o'], ['g', 'r', 'ü', 'ß', 'e']] {
+            let encoded = encode_word(&word);
+            assert!(encoded.chars().all(|c| c.is_ascii_hexdigit()));
+            assert!(!encoded.contains(&word.iter().collect::<String>()));
+            assert_eq!(decode_word(&encoded), Some(word));
+        }
+    }
+
+    #[test]
+    fn test_share_code_decodes_to_solution() {
+        let solution = ['c', 'r', 'a', 'n', 'e'];
+        let game = Game::new_with_word(DEFAULT_MAX_ATTEMPTS, Language::English, solution).unwrap();
+        assert_eq!(decode_word(&game.share_code()), Some(solution));
+    }
+
+    #[test]
+    fn test_decode_word_rejects_garbage() {
+        assert_eq!(decode_word(""), None);
+        assert_eq!(decode_word("abc"), None);
+        assert_eq!(decode_word("zzzzzzzzzz"), None);
+        // Valid encoding, but of a 4-letter word
+        let four_letters: String = encode_word(&['h', 'e', 'l', 'l', 'o'])[..8].to_string();
+        assert_eq!(decode_word(&four_letters), None);
+    }
+
+    #[test]
+    fn test_new_with_word_uses_given_solution() {
+        let word = Language::English.wordlist_array()[0];
+        let mut game = Game::new_with_word(6, Language::English, word).unwrap();
+
+        assert_eq!(game.language(), Language::English);
+        assert_eq!(game.max_attempts(), 6);
+        assert!(matches!(game.take_guess(&word), Ok(GuessResult::Won(_))));
+    }
+
+    /// A few 4-letter words, sorted like `include_wordlist!` emits them
+    const WORDLIST_4: &[[char; 4]] = &[
+        ['b', 'e', 'a', 'r'],
+        ['b', 'o', 'a', 'r'],
+        ['d', 'e', 'a', 'r'],
+        ['r', 'e', 'a', 'd'],
+        ['r', 'o', 'a', 'd'],
+    ];
+
+    #[test]
+    fn test_four_letter_game() {
+        use LetterResult::*;
+
+        let mut game: Game<4> =
+            Game::new_with_wordlist(6, Language::English, WORDLIST_4, ['r', 'o', 'a', 'd'])
+                .unwrap();
+        assert!(matches!(
+            game.take_guess(&['w', 'o', 'r', 'd']),
+            Err(GameError::WordNotInList)
+        ));
+
+        assert_eq!(
+            game.take_guess(&['d', 'e', 'a', 'r']).unwrap(),
+            GuessResult::Continue([Misplaced, Absent, Correct, Misplaced])
+        );
+        assert_eq!(
+            game.take_guess(&['b', 'o', 'a', 'r']).unwrap(),
+            GuessResult::Continue([Absent, Correct, Correct, Misplaced])
+        );
+        assert_eq!(
+            game.take_guess(&['r', 'o', 'a', 'd']).unwrap(),
+            GuessResult::Won([Correct; 4])
+        );
+        assert_eq!(game.attempts(), 3);
+        assert!(game.is_finished());
+        assert_eq!(game.letter_states()[&'b'], Absent);
+    }
+
+    #[test]
+    fn test_new_with_wordlist_rejects_solution_not_in_list() {
+        let result =
+            Game::new_with_wordlist(6, Language::English, WORDLIST_4, ['w', 'o', 'r', 'd']);
+        assert!(matches!(result, Err(GameError::WordNotInList)));
+    }
+
+    /// A hard game of 'crane' after guessing 'trace', which finds R, A and E in place and C
+    /// elsewhere
+    fn hard_game_after_trace() -> Game {
+        let mut game = Game::new_with_word(6, Language::English, ['c', 'r', 'a', 'n', 'e'])
+            .unwrap()
+            .with_hard_mode();
+        assert!(game.is_hard_mode());
+        game.take_guess(&['t', 'r', 'a', 'c', 'e']).unwrap();
+        game
+    }
+
+    #[test]
+    fn test_hard_mode_rejects_moved_correct_letter() {
+        let mut game = hard_game_after_trace();
+        // Uses every letter, but moves the R and A
+        let result = game.take_guess(&['c', 'a', 'r', 'e', 's']);
+        assert!(matches!(result, Err(GameError::ViolatesHardMode)));
+        assert_eq!(game.attempts(), 1);
+    }
+
+    #[test]
+    fn test_hard_mode_rejects_missing_misplaced_letter() {
+        let mut game = hard_game_after_trace();
+        // Keeps R, A and E in place, but leaves out the C
+        let grade = ['g', 'r', 'a', 'd', 'e'];
+        assert!(matches!(
+            game.take_guess(&grade),
+            Err(GameError::ViolatesHardMode)
+        ));
+
+        // The same guess is fine outside hard mode
+        let mut easy_game =
+            Game::new_with_word(6, Language::English, ['c', 'r', 'a', 'n', 'e']).unwrap();
+        easy_game.take_guess(&['t', 'r', 'a', 'c', 'e']).unwrap();
+        assert!(easy_game.take_guess(&grade).is_ok());
+    }
+
+    #[test]
+    fn test_hard_mode_accepts_guess_using_all_hints() {
+        let mut game = hard_game_after_trace();
+        assert!(matches!(
+            game.take_guess(&['b', 'r', 'a', 'c', 'e']),
+            Ok(GuessResult::Continue(_))
+        ));
+        assert!(matches!(
+            game.take_guess(&['c', 'r', 'a', 'n', 'e']),
+            Ok(GuessResult::Won(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_with_word_rejects_word_not_in_list() {
+        let result = Game::new_with_word(6, Language::English, ['z', 'z', 'z', 'z', 'z']);
+        assert!(matches!(result, Err(GameError::WordNotInList)));
+    }
+
+    #[test]
+    fn test_contains() {
+        let english = Language::English.wordlist_array();
+        for word in [
+            english[0],
+            english[english.len() / 2],
+            english[english.len() - 1],
+        ] {
+            assert!(Language::English.contains(&word));
+        }
+        assert!(!Language::English.contains(&['z', 'z', 'z', 'z', 'z']));
+        assert!(!Language::German.contains(&['c', 'r', 'a', 'n', 'e']));
+    }
+
+    #[test]
+    fn test_wordlists_sorted_and_unique() {
+        for &language in Language::all() {
+            let words = language.wordlist_array();
+            assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_recommended_openers() {
+        for &language in Language::all() {
+            let openers = language.recommended_openers();
+            assert!(!openers.is_empty());
+            for opener in openers {
+                assert!(language.contains(opener));
+                assert_eq!(opener.iter().collect::<HashSet<_>>().len(), 5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_word() {
+        for &language in Language::all() {
+            let word = language.random_word();
+            assert!(language.contains(&word));
+
+            let seeded = language.random_word_seeded(42);
+            assert!(language.contains(&seeded));
+            assert_eq!(language.random_word_seeded(42), seeded);
+        }
+    }
+
+    #[test]
+    fn test_try_random_word() {
+        for language in Language::all() {
+            let word = language.try_random_word().unwrap();
+            assert!(language.contains(&word));
+        }
+    }
+
+    #[test]
+    fn test_random_word_seeded_varies_with_seed() {
+        let words: HashSet<[char; 5]> = (0..20)
+            .map(|seed| Language::English.random_word_seeded(seed))
+            .collect();
+        assert!(words.len() > 1);
+    }
+
+    #[test]
+    fn test_no_guess_after_early_win() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        let mut game = Game::new_with_word(6, Language::English, crane).unwrap();
+        assert!(matches!(game.take_guess(&crane), Ok(GuessResult::Won(_))));
+        assert!(game.is_finished());
+
+        // Attempts are left, but the game is over
+        assert!(game.has_attempts_left());
+        assert!(matches!(
+            game.take_guess(&['s', 'l', 'a', 't', 'e']),
+            Err(GameError::GameOver)
+        ));
+        assert_eq!(game.attempts(), 1);
+        assert_eq!(game.guesses().len(), 1);
+    }
+
+    #[test]
+    fn test_on_result_callback() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        let slate = ['s', 'l', 'a', 't', 'e'];
+        let mut game = Game::new_with_word(6, Language::English, crane).unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        game.set_on_result(Box::new(move |result| {
+            recorded.lock().unwrap().push(match result {
+                GuessResult::Continue(_) => "continue",
+                GuessResult::Won(_) => "won",
+                GuessResult::Lost { .. } => "lost",
+            });
+        }));
+
+        game.take_guess(&slate).unwrap();
+        // Rejected guesses are not reported
+        assert!(game.take_guess(&['x', 'x', 'x', 'x', 'x']).is_err());
+        // Clones don't report to the original's callback
+        let mut clone = game.clone();
+        clone.take_guess(&crane).unwrap();
+
+        game.take_guess(&crane).unwrap();
+        assert!(game.take_guess(&crane).is_err());
+        assert_eq!(*events.lock().unwrap(), vec!["continue", "won"]);
+
+        let mut game = Game::new_with_word(6, Language::English, crane).unwrap();
+        let recorded = Arc::clone(&events);
+        game.set_on_result(Box::new(move |_| recorded.lock().unwrap().push("cleared")));
+        game.clear_on_result();
+        game.take_guess(&crane).unwrap();
+        assert_eq!(events.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_all_languages() {
+        assert_eq!(Language::all(), [Language::English, Language::German]);
+        // Sorted, so iterating a map keyed by language visits them in the same order
+        assert!(Language::all().is_sorted());
+    }
+
+    #[test]
+    fn test_language_and_guess_result_in_collections() {
+        let mut per_language = std::collections::BTreeMap::new();
+        per_language.insert(Language::German, 2);
+        per_language.insert(Language::English, 1);
+        assert_eq!(
+            per_language.keys().copied().collect::<Vec<_>>(),
+            vec![Language::English, Language::German]
+        );
+
+        let mut game =
+            Game::new_with_word(6, Language::English, ['c', 'r', 'a', 'n', 'e']).unwrap();
+        let result = game.take_guess(&['c', 'r', 'a', 'n', 'e']).unwrap();
+        assert_eq!(result, GuessResult::Won([LetterResult::Correct; 5]));
+        let results: HashSet<GuessResult> = [result.clone(), result].into_iter().collect();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_same_seed_picks_same_solution() {
+        for &language in Language::all() {
+            let first = Game::new_with_seed(6, language, 7).unwrap();
+            let second = Game::new_with_seed(6, language, 7).unwrap();
+            assert_eq!(first.solution_index(), second.solution_index());
+            assert_eq!(first.solution, second.solution);
+        }
+    }
+
+    #[test]
+    fn test_daily_game() {
+        for &language in Language::all() {
+            let today = Game::daily(language, 20_000).unwrap();
+            let again = Game::daily(language, 20_000).unwrap();
+            assert_eq!(today.solution, again.solution);
+            assert_eq!(today.max_attempts(), DEFAULT_MAX_ATTEMPTS);
+            // Not every pair of days differs, but a whole week of the same word would be a bug
+            let week: HashSet<_> = (20_000..20_007)
+                .map(|day| Game::daily(language, day).unwrap().solution)
+                .collect();
+            assert!(week.len() > 1);
+        }
+        // Pinned, so a change to the hash that would reshuffle every player's puzzles is caught
+        assert_eq!(split_mix(0), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_solution_index_recreates_game() {
+        let game = Game::new(6, Language::German).unwrap();
+        let word = Language::German.wordlist_array()[game.solution_index()];
+        let mut replay = Game::new_with_word(6, Language::German, word).unwrap();
+        assert_eq!(replay.solution_index(), game.solution_index());
+        assert!(matches!(
+            replay.take_guess(&game.solution),
+            Ok(GuessResult::Won(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_game_with_seed() {
+        for &language in Language::all() {
+            let mut game = Game::new_with_seed(6, language, 42).unwrap();
+            let solution = language.random_word_seeded(42);
+            assert!(matches!(
+                game.take_guess(&solution),
+                Ok(GuessResult::Won(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_new_no_repeats_game() {
+        for &language in Language::all() {
+            for _ in 0..20 {
+                let game = Game::new_no_repeats(6, language).unwrap();
+                let unique: std::collections::HashSet<char> =
+                    game.solution.iter().copied().collect();
+                assert_eq!(unique.len(), 5);
+                assert!(language.contains(&game.solution));
+            }
+        }
+        assert!(has_distinct_letters(&['c', 'r', 'a', 'n', 'e']));
+        assert!(!has_distinct_letters(&['a', 'p', 'p', 'l', 'e']));
+        assert!(!has_distinct_letters(&['e', 'e', 'r', 'i', 'e']));
+    }
+
+    #[test]
+    fn test_new_weighted_game() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        let slate = ['s', 'l', 'a', 't', 'e'];
+        for _ in 0..20 {
+            let game = Game::new_weighted(6, Language::English, |word| {
+                if *word == crane || *word == slate {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+            .unwrap();
+            assert!(game.solution == crane || game.solution == slate);
+        }
+
+        assert!(matches!(
+            Game::new_weighted(6, Language::English, |_| 0.0),
+            Err(WordListError::WordListEmpty)
+        ));
+    }
+
+    #[test]
+    fn test_word_difficulty() {
+        let english = Language::English;
+        // The opener itself is found with its own feedback, all green
+        assert_eq!(english.word_difficulty(&['s', 'l', 'a', 't', 'e']), 0.0);
+
+        // Words ending in -atch all look alike after 'slate', unlike 'crane'
+        let hatch = english.word_difficulty(&['h', 'a', 't', 'c', 'h']);
+        let crane = english.word_difficulty(&['c', 'r', 'a', 'n', 'e']);
+        assert!(hatch > crane);
+        assert!(hatch.is_finite() && crane >= 0.0);
+    }
+
+    #[test]
+    fn test_describe_difficulty() {
+        assert_eq!(
+            Language::English.describe_difficulty(&['s', 'l', 'a', 't', 'e']),
+            "Difficulty: 0.0 bits (1 words look alike after SLATE)"
+        );
+    }
+
+    #[test]
+    fn test_no_guess_after_loss() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        let slate = ['s', 'l', 'a', 't', 'e'];
+        let mut game = Game::new_with_word(1, Language::English, crane).unwrap();
+        assert!(matches!(
+            game.take_guess(&slate),
+            Ok(GuessResult::Lost { .. })
+        ));
+        assert!(game.is_finished());
+        assert!(matches!(game.take_guess(&crane), Err(GameError::GameOver)));
+        assert_eq!(game.attempts(), 1);
+    }
+
+    #[test]
+    fn test_letter_states_repeated_letter_in_one_guess() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        let mut game = Game::new_with_word(6, Language::English, crane).unwrap();
+        game.take_guess(&['e', 'e', 'r', 'i', 'e']).unwrap();
+
+        let states = game.letter_states();
+        // The first two e's are Absent, the last one Correct
+        assert_eq!(states[&'e'], LetterResult::Correct);
+        assert_eq!(states[&'r'], LetterResult::Misplaced);
+        assert_eq!(states[&'i'], LetterResult::Absent);
+        assert_eq!(states.get(&'c'), None);
+        assert_eq!(game.guesses().len(), 1);
+    }
+
+    #[test]
+    fn test_letter_states_never_downgrade() {
+        let mut game =
+            Game::new_with_word(6, Language::English, ['h', 'e', 'l', 'l', 'o']).unwrap();
+        game.guesses = vec![
+            (
+                ['a', 'b', 'c', 'd', 'e'],
+                [
+                    LetterResult::Correct,
+                    LetterResult::Misplaced,
+                    LetterResult::Absent,
+                    LetterResult::Absent,
+                    LetterResult::Absent,
+                ],
+            ),
+            (
+                ['b', 'a', 'c', 'e', 'x'],
+                [
+                    LetterResult::Correct,
+                    LetterResult::Absent,
+                    LetterResult::Misplaced,
+                    LetterResult::Absent,
+                    LetterResult::Absent,
+                ],
+            ),
+        ];
+
+        let states = game.letter_states();
+        // A later Absent doesn't downgrade an earlier Correct
+        assert_eq!(states[&'a'], LetterResult::Correct);
+        // A later Correct or Misplaced upgrades an earlier state
+        assert_eq!(states[&'b'], LetterResult::Correct);
+        assert_eq!(states[&'c'], LetterResult::Misplaced);
+        assert_eq!(states[&'e'], LetterResult::Absent);
+        assert_eq!(states[&'x'], LetterResult::Absent);
+        assert_eq!(letter_states(game.guesses()), states);
+    }
+
+    /// English letters plus the apostrophe, like French elisions need
+    const APOSTROPHE_CHARS: CharSet = CharSet {
+        alphabet: ALPHABET_EN,
+        extra_chars: &['\''],
+    };
+
+    #[test]
+    fn test_is_valid_char() {
+        for &language in Language::all() {
+            assert!(language.is_valid_char('a'));
+            assert!(language.is_valid_char('A'));
+            assert!(!language.is_valid_char('\''));
+            assert!(!language.is_valid_char('-'));
+            assert!(!language.is_valid_char('1'));
+        }
+
+        // Each language only accepts its own letters
+        assert!(Language::German.is_valid_char('ß'));
+        assert!(Language::German.is_valid_char('Ü'));
+        assert!(Language::German.is_valid_char('é'));
+        assert!(!Language::English.is_valid_char('ß'));
+        assert!(!Language::English.is_valid_char('é'));
+        assert!(!Language::English.is_valid_char('ж'));
+        assert!(!Language::German.is_valid_char('ж'));
+    }
+
+    #[test]
+    fn test_wordlists_only_contain_valid_chars() {
+        for &language in Language::all() {
+            assert!(
+                language
+                    .wordlist_array()
+                    .iter()
+                    .flatten()
+                    .all(|&c| language.is_valid_char(c))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_wordlist_without_apostrophes() {
+        let words = Language::English.parse_wordlist("Hello\nl'eau\nab-cd\ntoolong\nworld\n");
+        assert_eq!(
+            words,
+            vec![['h', 'e', 'l', 'l', 'o'], ['w', 'o', 'r', 'l', 'd']]
+        );
+    }
+
+    #[test]
+    fn test_parse_wordlist_with_apostrophes() {
+        // A language that allows apostrophes, like French
+        let words = parse_wordlist("l'eau\nab-cd\nhello\n", APOSTROPHE_CHARS);
+        assert_eq!(
+            words,
+            vec![['l', '\'', 'e', 'a', 'u'], ['h', 'e', 'l', 'l', 'o']]
+        );
+        assert!(APOSTROPHE_CHARS.contains('\''));
+        assert!(!APOSTROPHE_CHARS.contains('-'));
+        assert!(!Language::English.char_set().contains('\''));
+    }
+
+    #[test]
+    fn test_display_upper() {
+        assert_eq!(display_upper('a'), 'A');
+        assert_eq!(display_upper('ä'), 'Ä');
+        assert_eq!(display_upper('A'), 'A');
+        // Would become "SS"
+        assert_eq!(display_upper('ß'), 'ß');
+        // Would become "ʼN"
+        assert_eq!(display_upper('ŉ'), 'ŉ');
+    }
+
+    #[test]
+    fn test_lowercase_letter() {
+        assert_eq!(lowercase_letter('A'), 'a');
+        assert_eq!(lowercase_letter('Ü'), 'ü');
+        assert_eq!(lowercase_letter('ß'), 'ß');
+        // The capital sharp s
+        assert_eq!(lowercase_letter('ẞ'), 'ß');
+        // Would become "i̇"
+        assert_eq!(lowercase_letter('İ'), 'İ');
+        assert_eq!(lowercase_word("STRAẞE"), vec!['s', 't', 'r', 'a', 'ß', 'e']);
+    }
+
+    #[test]
+    fn test_normalize_guess() {
+        let crane = ['c', 'r', 'a', 'n', 'e'];
+        assert_eq!(
+            normalize_guess(" CRane\n", Language::English).unwrap(),
+            crane
+        );
+        assert_eq!(
+            normalize_guess("GRÜßE", Language::German).unwrap(),
+            ['g', 'r', 'ü', 'ß', 'e']
+        );
+        assert!(matches!(
+            normalize_guess("cranes", Language::English),
+            Err(GameError::WrongLength)
+        ));
+        assert!(matches!(
+            normalize_guess("", Language::English),
+            Err(GameError::WrongLength)
+        ));
+        assert!(matches!(
+            normalize_guess("cr4ne", Language::English),
+            Err(GameError::InvalidChar('4'))
+        ));
+        // Spaces inside the word are not trimmed
+        assert!(matches!(
+            normalize_guess("cr ne", Language::English),
+            Err(GameError::InvalidChar(' '))
+        ));
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        use LetterResult::{Absent, Correct, Misplaced};
+
+        let expected = [Correct, Misplaced, Absent, Absent, Correct];
+        assert_eq!(parse_feedback("GYBBG").unwrap(), expected);
+        assert_eq!(parse_feedback("gybbg").unwrap(), expected);
+        assert_eq!(parse_feedback("CmAaG").unwrap(), expected);
+        assert_eq!(format_feedback(&expected), "GYBBG");
+        assert_eq!(
+            parse_feedback::<4>("GGYB").unwrap(),
+            [Correct, Correct, Misplaced, Absent]
+        );
+
+        assert!(matches!(
+            parse_feedback::<5>("GYBB"),
+            Err(GameError::WrongLength)
+        ));
+        assert!(matches!(
+            parse_feedback::<5>("GYXBG"),
+            Err(GameError::InvalidChar('X'))
+        ));
+    }
+
+    #[test]
+    fn test_cycle_feedback() {
+        for result in [
+            LetterResult::Correct,
+            LetterResult::Misplaced,
+            LetterResult::Absent,
+        ] {
+            // Three steps in either direction come back around, and each undoes the other
+            let mut forward = result;
+            for _ in 0..3 {
+                forward = cycle_feedback(Some(forward), true);
+            }
+            assert_eq!(forward, result);
+            assert_eq!(
+                cycle_feedback(Some(cycle_feedback(Some(result), true)), false),
+                result
+            );
+        }
+        assert_eq!(cycle_feedback(None, true), LetterResult::Misplaced);
+        assert_eq!(cycle_feedback(None, false), LetterResult::Absent);
+    }
+
+    #[test]
+    fn test_alphabet() {
+        let english = Language::English.alphabet();
+        assert!(
+            Language::English
+                .wordlist_array()
+                .iter()
+                .flatten()
+                .all(|c| english.contains(c))
+        );
+
+        // German words keep their umlauts and ß
+        let german = Language::German.alphabet();
+        assert!(german.starts_with(english));
+        for letter in ['ä', 'ö', 'ü', 'ß'] {
+            assert!(german.contains(&letter));
+            assert!(!english.contains(&letter));
+        }
+        assert!(Language::German.contains(&['b', 'l', 'ü', 't', 'e']));
+        assert!(Language::German.contains(&['a', 'u', 'ß', 'e', 'n']));
+    }
 }
@@ -2,31 +2,103 @@ use proc_macro::TokenStream;
 use quote::quote;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use syn::{LitStr, parse_macro_input};
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::{LitInt, LitStr, Token, parse_macro_input};
 
+/// Length of the words embedded when the macro is given no length, as in classic Wordle
+const DEFAULT_WORD_LENGTH: usize = 5;
+
+/// The macro's arguments: a file name and, optionally, the length of the words to keep
+struct Args {
+    filename: LitStr,
+    length: usize,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let filename = input.parse()?;
+        let length = if input.is_empty() {
+            DEFAULT_WORD_LENGTH
+        } else {
+            input.parse::<Token![,]>()?;
+            input.parse::<LitInt>()?.base10_parse()?
+        };
+        Ok(Self { filename, length })
+    }
+}
+
+/// Where to look for a word list, in order: relative to the invoking crate's manifest
+/// directory, then its parent (the workspace root), then the working directory
+///
+/// `CARGO_MANIFEST_DIR` is read while the macro expands, so it belongs to the crate calling
+/// the macro, and the result doesn't depend on where cargo was invoked from.
+fn candidate_paths(filename: &str) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        let manifest_dir = Path::new(&manifest_dir);
+        paths.push(manifest_dir.join(filename));
+        if let Some(parent) = manifest_dir.parent() {
+            paths.push(parent.join(filename));
+        }
+    }
+    paths.push(PathBuf::from(filename));
+    paths.dedup();
+    paths
+}
+
+/// Lowercase a letter, keeping it as is if its lowercase form is more than one character
+///
+/// Mirrors `wordle_core::lowercase_letter`, which this crate can't depend on. Lowercasing
+/// whole lines with `str::to_lowercase` could change their length and drop or split words.
+fn lowercase_letter(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+/// Embed a word list file as an array of 5-letter words (`[[char; 5]; N]`), or of words of
+/// another length with `include_wordlist!("words.txt", 4)`
+///
+/// Words are lowercased letter by letter (keeping umlauts and ß), sorted and deduplicated;
+/// lines that are not exactly as long as requested are skipped. If the file is not found in
+/// any of the locations from `candidate_paths`, a compile error lists the paths tried.
 #[proc_macro]
 pub fn include_wordlist(input: TokenStream) -> TokenStream {
-    let filename = parse_macro_input!(input as LitStr).value();
-    let file =
-        File::open(&filename).unwrap_or_else(|e| panic!("Error opening file {}: {}", filename, e));
+    let Args {
+        filename: literal,
+        length,
+    } = parse_macro_input!(input as Args);
+    let filename = literal.value();
+
+    let paths = candidate_paths(&filename);
+    let Some(file) = paths.iter().find_map(|path| File::open(path).ok()) else {
+        let tried = paths
+            .iter()
+            .map(|path| format!("  {}", path.display()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let message = format!("word list '{}' not found, tried:\n{}", filename, tried);
+        return syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into();
+    };
 
-    let words = BufReader::new(file)
+    let mut words = BufReader::new(file)
         .lines()
         .filter_map(|line| {
             let s = line.ok()?;
-            let chars: Vec<char> = s.to_lowercase().chars().collect();
-            if chars.len() == 5 { Some(chars) } else { None }
+            let chars: Vec<char> = s.chars().map(lowercase_letter).collect();
+            (chars.len() == length).then_some(chars)
         })
         .collect::<Vec<_>>();
+    // Sorted and unique, so the array supports binary search and iterates in a stable order
+    words.sort();
+    words.dedup();
 
-    let arrays = words.iter().map(|chars| {
-        let c0 = chars[0];
-        let c1 = chars[1];
-        let c2 = chars[2];
-        let c3 = chars[3];
-        let c4 = chars[4];
-        quote!([#c0, #c1, #c2, #c3, #c4])
-    });
+    let arrays = words.iter().map(|chars| quote!([#(#chars),*]));
 
     TokenStream::from(quote! {
         [#(#arrays),*]
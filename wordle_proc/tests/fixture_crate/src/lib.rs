@@ -0,0 +1,34 @@
+//! A crate one level below `wordle_proc/tests`, so `include_wordlist!` can be tested from a
+//! manifest directory whose parent holds the shared fixtures
+use wordle_proc::include_wordlist;
+
+/// Found next to this crate's own manifest
+pub const OWN_WORDS: &[[char; 5]] = &include_wordlist!("words.txt");
+
+/// Not next to this crate's manifest, only found in its parent directory
+pub const PARENT_WORDS: &[[char; 5]] = &include_wordlist!("fixtures/words.txt");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_include_wordlist_relative_to_manifest_dir() {
+        assert_eq!(
+            OWN_WORDS,
+            &[['a', 'b', 'o', 'u', 't'], ['h', 'e', 'l', 'l', 'o']]
+        );
+    }
+
+    #[test]
+    fn test_include_wordlist_falls_back_to_parent_dir() {
+        assert_eq!(
+            PARENT_WORDS,
+            &[
+                ['a', 'd', 'i', 'e', 'u'],
+                ['c', 'r', 'a', 'n', 'e'],
+                ['s', 'l', 'a', 't', 'e'],
+            ]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use wordle_proc::include_wordlist;
+
+// Resolved against this crate's manifest directory, not the directory cargo runs in
+const WORDS: &[[char; 5]] = &include_wordlist!("tests/fixtures/words.txt");
+const DUPLICATES: &[[char; 5]] = &include_wordlist!("tests/fixtures/duplicates.txt");
+const GERMAN: &[[char; 5]] = &include_wordlist!("tests/fixtures/german.txt");
+const LONG_WORDS: &[[char; 7]] = &include_wordlist!("tests/fixtures/words.txt", 7);
+
+#[test]
+fn test_include_wordlist_relative_to_manifest_dir() {
+    assert_eq!(
+        WORDS,
+        &[
+            ['a', 'd', 'i', 'e', 'u'],
+            ['c', 'r', 'a', 'n', 'e'],
+            ['s', 'l', 'a', 't', 'e'],
+        ]
+    );
+}
+
+#[test]
+fn test_include_wordlist_sorts_and_deduplicates() {
+    // 7 lines, but 'slate' appears three times and 'crane' twice (once uppercase)
+    assert_eq!(DUPLICATES.len(), 3);
+    assert!(DUPLICATES.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn test_include_wordlist_keeps_umlauts_and_sharp_s() {
+    // The capital sharp s in 'MAẞEN' becomes ß, and 'STRAẞE' has six letters
+    assert_eq!(
+        GERMAN,
+        &[
+            ['a', 'u', 'ß', 'e', 'n'],
+            ['b', 'l', 'ü', 't', 'e'],
+            ['m', 'a', 'ß', 'e', 'n'],
+        ]
+    );
+}
+
+#[test]
+fn test_include_wordlist_with_word_length() {
+    // Only 'toolong' has seven letters, the 5-letter words are skipped
+    assert_eq!(LONG_WORDS, &[['t', 'o', 'o', 'l', 'o', 'n', 'g']]);
+}
@@ -0,0 +1,53 @@
+use ratatui::{
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+};
+use wordle_core::{LetterResult, display_upper};
+
+/// Background of a tile whose letter has no result (yet)
+pub const EMPTY_COLOR: Color = Color::Black;
+
+/// Background of a tile with the given result
+pub fn result_color(result: LetterResult) -> Color {
+    match result {
+        LetterResult::Correct => Color::Green,
+        LetterResult::Misplaced => Color::Yellow,
+        LetterResult::Absent => Color::DarkGray,
+    }
+}
+
+/// A letter tile on the given background, uppercased for display (ß stays ß)
+///
+/// A selected tile is marked like `<A>` instead of ` A `, so every tile has the same width.
+pub fn tile(letter: char, background: Color, selected: bool) -> Span<'static> {
+    let (before, after) = if selected { ('<', '>') } else { (' ', ' ') };
+    // Dark text only reads well on the bright result colors
+    let foreground = match background {
+        Color::Green | Color::Yellow => Color::Black,
+        _ => Color::White,
+    };
+    Span::styled(
+        format!("{}{}{}", before, display_upper(letter), after),
+        Style::default().fg(foreground).bg(background).bold(),
+    )
+}
+
+/// A guessed word as a row of tiles colored by its feedback
+pub fn word_row(word: &[char; 5], results: &[LetterResult; 5]) -> Line<'static> {
+    word.iter()
+        .zip(results)
+        .map(|(&letter, &result)| tile(letter, result_color(result), false))
+        .collect()
+}
+
+/// A word as a row of tiles on the same background, e.g. for a recommendation or typed input
+pub fn plain_row(word: &[char], background: Color) -> Line<'static> {
+    word.iter()
+        .map(|&letter| tile(letter, background, false))
+        .collect()
+}
+
+/// A row of five blank tiles, for guesses not made yet
+pub fn empty_row() -> Line<'static> {
+    plain_row(&[' '; 5], EMPTY_COLOR)
+}
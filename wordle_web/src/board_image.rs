@@ -0,0 +1,66 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlAnchorElement, HtmlCanvasElement};
+use wordle_core::{LetterResult, display_upper};
+
+const TILE_SIZE: f64 = 62.0;
+const TILE_GAP: f64 = 5.0;
+const PADDING: f64 = 16.0;
+
+/// Draw the guessed rows onto a canvas and download it as `wordle.png`
+///
+/// The canvas is scaled by the device pixel ratio, so the image is sharp on high-DPI screens.
+pub fn save_board_image(guesses: &[([char; 5], [LetterResult; 5])]) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let document = window.document().ok_or("no document")?;
+
+    let rows = guesses.len() as f64;
+    let width = 2.0 * PADDING + 5.0 * TILE_SIZE + 4.0 * TILE_GAP;
+    let height = 2.0 * PADDING + rows * TILE_SIZE + (rows - 1.0).max(0.0) * TILE_GAP;
+    let ratio = window.device_pixel_ratio();
+
+    let canvas: HtmlCanvasElement = document.create_element("canvas")?.dyn_into()?;
+    canvas.set_width((width * ratio).round() as u32);
+    canvas.set_height((height * ratio).round() as u32);
+
+    let context: CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or("no 2d context")?
+        .dyn_into()?;
+    context.scale(ratio, ratio)?;
+
+    // Background
+    context.set_fill_style_str("#ffffff");
+    context.fill_rect(0.0, 0.0, width, height);
+
+    // Tiles, with the same colors as the board
+    context.set_font("bold 32px 'Open Sans', sans-serif");
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    for (row, (word, results)) in guesses.iter().enumerate() {
+        let y = PADDING + row as f64 * (TILE_SIZE + TILE_GAP);
+        for (column, (&letter, &result)) in word.iter().zip(results).enumerate() {
+            let x = PADDING + column as f64 * (TILE_SIZE + TILE_GAP);
+            context.set_fill_style_str(match result {
+                LetterResult::Correct => "#6aaa64",
+                LetterResult::Misplaced => "#c9b458",
+                LetterResult::Absent => "#787c7e",
+            });
+            context.fill_rect(x, y, TILE_SIZE, TILE_SIZE);
+
+            context.set_fill_style_str("#ffffff");
+            context.fill_text(
+                &display_upper(letter).to_string(),
+                x + TILE_SIZE / 2.0,
+                y + TILE_SIZE / 2.0,
+            )?;
+        }
+    }
+
+    // Trigger the download through a temporary link
+    let link: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    link.set_href(&canvas.to_data_url_with_type("image/png")?);
+    link.set_download("wordle.png");
+    link.click();
+
+    Ok(())
+}
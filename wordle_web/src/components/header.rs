@@ -10,6 +10,9 @@ pub fn Header(
     show_nav: bool,
     nav_to: Option<&'static str>,
     nav_label: Option<&'static str>,
+    /// Short text shown next to the controls, like the seed of a seeded game
+    #[prop(optional)]
+    badge: Option<Signal<Option<String>>>,
 ) -> impl IntoView {
     let navigate = use_navigate();
 
@@ -38,6 +41,11 @@ pub fn Header(
         <div class="header">
             <div class="header__title">{title}</div>
             <div class="header__controls">
+                {move || {
+                    badge
+                        .and_then(|badge| badge.get())
+                        .map(|text| view! { <span class="header__badge">{text}</span> })
+                }}
                 {nav_content}
                 <select
                     class="language-select"
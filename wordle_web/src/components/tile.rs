@@ -1,14 +1,5 @@
 use leptos::prelude::*;
-use wordle_core::LetterResult;
-
-/// Display a character in uppercase, but preserve ß instead of converting to SS
-fn uppercase_display(c: char) -> char {
-    if c == 'ß' {
-        c
-    } else {
-        c.to_uppercase().next().unwrap_or(c)
-    }
-}
+use wordle_core::{LetterResult, display_upper};
 
 #[component]
 pub fn Tile(
@@ -35,7 +26,7 @@ pub fn Tile(
     }
 
     let class = classes.join(" ");
-    let ch_str = uppercase_display(letter).to_string();
+    let ch_str = display_upper(letter).to_string();
 
     view! {
         <div class=class>
@@ -49,6 +40,7 @@ pub fn InteractiveTile<F>(
     letter: char,
     result: Option<LetterResult>,
     #[prop(optional)] small: bool,
+    #[prop(optional)] focused: bool,
     on_click: F,
 ) -> impl IntoView
 where
@@ -60,6 +52,10 @@ where
         classes.push("tile--small");
     }
 
+    if focused {
+        classes.push("tile--focused");
+    }
+
     match result {
         None => classes.push("tile--default"),
         Some(LetterResult::Absent) => classes.push("tile--absent"),
@@ -68,7 +64,7 @@ where
     }
 
     let class = classes.join(" ");
-    let ch_str = uppercase_display(letter).to_string();
+    let ch_str = display_upper(letter).to_string();
 
     view! {
         <div class=class on:click=move |_| on_click()>
@@ -4,6 +4,7 @@ use leptos_router::{
     path,
 };
 
+mod board_image;
 mod components;
 mod pages;
 mod styles;
@@ -1,21 +1,62 @@
 use leptos::prelude::*;
+use serde_json::{Value, json};
+use std::collections::HashSet;
 use wordle_ai::{HeuristicGuesser, WordleAI};
-use wordle_core::{Language, LetterResult};
+use wordle_core::{
+    DEFAULT_MAX_ATTEMPTS, Language, LetterResult, cycle_feedback, display_upper, format_feedback,
+    letter_states, normalize_guess,
+};
 
+use super::{copy_to_clipboard, toggle_row};
 use crate::components::{Footer, Header, InteractiveTile, MessageBanner, MessageType, Tile};
 
+/// Number of recommended words offered to choose from
+const NUM_SUGGESTIONS: usize = 3;
+
+/// Number of remaining words listed when entering own guesses
+const MAX_LISTED_CANDIDATES: usize = 100;
+
 #[component]
 pub fn AiSolver() -> impl IntoView {
     // State
     let (language, set_language) = signal(Language::English);
     let mut initial_ai = HeuristicGuesser::new(Language::English.wordlist_array().to_vec());
-    let initial_recommendation = initial_ai.make_guess();
+    let initial_suggestions = initial_ai.suggest_top_n(NUM_SUGGESTIONS);
     let (ai, set_ai) = signal(initial_ai);
-    let (recommendation, set_recommendation) = signal(initial_recommendation);
+    let (recommendation, set_recommendation) = signal(initial_suggestions.first().copied());
+    let (suggestions, set_suggestions) = signal(initial_suggestions);
     let (feedback, set_feedback) = signal([None::<LetterResult>; 5]);
     let (history, set_history) = signal(Vec::<([char; 5], [LetterResult; 5])>::new());
     let (message, set_message) = signal(None::<(String, MessageType)>);
     let (won, set_won) = signal(false);
+    let (focus_pos, set_focus_pos) = signal(0usize);
+    // Spoiler-safe mode hides past rows, e.g. while streaming, until each is clicked
+    let (spoiler_safe, set_spoiler_safe) = signal(false);
+    let (revealed, set_revealed) = signal(HashSet::<usize>::new());
+    // In own-guesses mode, the user types every guess and the AI only narrows the candidates
+    let (own_guesses, set_own_guesses) = signal(false);
+    let (guess_input, set_guess_input) = signal(String::new());
+    let (show_candidates, set_show_candidates) = signal(false);
+    // Whether the AI's reasoning for the recommendation is expanded
+    let (show_reasoning, set_show_reasoning) = signal(false);
+    // Candidates before and after the last feedback, to show how far it narrowed them down
+    let (narrowing, set_narrowing) = signal(None::<(usize, usize)>);
+
+    // The words to recommend next, or none when the user picks their own guesses
+    let suggest = move |ai: &mut HeuristicGuesser| {
+        if own_guesses.get_untracked() {
+            Vec::new()
+        } else {
+            ai.suggest_top_n(NUM_SUGGESTIONS)
+        }
+    };
+
+    // Set feedback for a position with the keyboard, like the terminal assistant
+    let set_feedback_at = move |pos: usize, result: LetterResult| {
+        if recommendation.get().is_some() && !won.get() {
+            set_feedback.update(|f| f[pos] = Some(result));
+        }
+    };
 
     // Toggle feedback for a position
     let toggle_feedback = move |pos: usize| {
@@ -32,7 +73,7 @@ pub fn AiSolver() -> impl IntoView {
     };
 
     // Submit feedback
-    let submit_feedback = move |_| {
+    let submit_feedback = move || {
         if feedback.get().iter().all(|f| f.is_some()) {
             if let Some(word) = recommendation.get() {
                 let fb: [LetterResult; 5] = [
@@ -48,6 +89,7 @@ pub fn AiSolver() -> impl IntoView {
                     set_history.update(|h| h.push((word, fb)));
                     set_won.set(true);
                     set_recommendation.set(None);
+                    set_suggestions.set(Vec::new());
                     set_feedback.set([None; 5]);
                     set_message.set(Some((
                         "Congratulations! You won!".to_string(),
@@ -56,16 +98,56 @@ pub fn AiSolver() -> impl IntoView {
                     return;
                 }
 
+                // Refuse feedback that no word could produce together with the earlier feedback
+                let contradiction = ai.with_untracked(|ai| {
+                    ai.knowledge()
+                        .and_then(|knowledge| knowledge.find_contradiction(word, fb))
+                });
+                if let Some(reason) = contradiction {
+                    set_message.set(Some((
+                        format!(
+                            "This feedback contradicts earlier feedback: {}. Please check the colors.",
+                            reason
+                        ),
+                        MessageType::Error,
+                    )));
+                    return;
+                }
+
                 // Update AI
                 let mut ai_val = ai.get_untracked();
+                let before = ai_val.remaining_candidates().map(<[_]>::len);
                 ai_val.update(word, fb);
-                let next = ai_val.make_guess();
+                let after = ai_val.remaining_candidates().map(<[_]>::len);
+                set_narrowing.set(before.zip(after));
+                let next_suggestions = suggest(&mut ai_val);
+                let next = next_suggestions.first().copied();
+                let out_of_words = match ai_val.remaining_candidates() {
+                    Some(candidates) if own_guesses.get_untracked() => candidates.is_empty(),
+                    _ => next.is_none(),
+                };
                 set_ai.set(ai_val);
                 set_history.update(|h| h.push((word, fb)));
                 set_recommendation.set(next);
+                set_suggestions.set(next_suggestions);
                 set_feedback.set([None; 5]);
-                set_message.set(if next.is_none() {
-                    Some(("No more words available!".to_string(), MessageType::Error))
+                set_focus_pos.set(0);
+
+                // Every submitted feedback used up one guess of the real game
+                let attempts = history.get_untracked().len();
+                set_message.set(if out_of_words {
+                    Some((
+                        format!("No more words available after {} guesses!", attempts),
+                        MessageType::Error,
+                    ))
+                } else if attempts >= DEFAULT_MAX_ATTEMPTS {
+                    Some((
+                        format!(
+                            "All {} guesses used: this would exceed Wordle's {} guesses!",
+                            attempts, DEFAULT_MAX_ATTEMPTS
+                        ),
+                        MessageType::Error,
+                    ))
                 } else {
                     None
                 });
@@ -83,10 +165,13 @@ pub fn AiSolver() -> impl IntoView {
         if let Some(word) = recommendation.get() {
             let mut ai_val = ai.get_untracked();
             ai_val.mark_invalid(word);
-            let next = ai_val.make_guess();
+            let next_suggestions = suggest(&mut ai_val);
+            let next = next_suggestions.first().copied();
             set_ai.set(ai_val);
             set_recommendation.set(next);
+            set_suggestions.set(next_suggestions);
             set_feedback.set([None; 5]);
+            set_focus_pos.set(0);
             set_message.set(if next.is_none() {
                 Some(("No more words available!".to_string(), MessageType::Error))
             } else {
@@ -95,30 +180,116 @@ pub fn AiSolver() -> impl IntoView {
         }
     };
 
-    // Change language
-    let change_language = move |new_lang: Language| {
-        let mut ai_val = HeuristicGuesser::new(new_lang.wordlist_array().to_vec());
-        let next = ai_val.make_guess();
-        set_language.set(new_lang);
+    // Copy the entered guesses and feedback to the clipboard, e.g. for a bug report
+    let copy_state = move |_| {
+        let state = solver_state_json(language.get(), &history.get());
+        set_message.set(Some(match copy_to_clipboard(&state) {
+            Ok(()) => (
+                "Solver state copied to clipboard".to_string(),
+                MessageType::Info,
+            ),
+            Err(_) => (
+                "Could not copy to clipboard!".to_string(),
+                MessageType::Error,
+            ),
+        }));
+    };
+
+    // Enter feedback for the word the user typed instead of a recommendation
+    let use_own_guess = move || match normalize_guess(&guess_input.get(), language.get()) {
+        Ok(word) => {
+            set_recommendation.set(Some(word));
+            set_feedback.set([None; 5]);
+            set_focus_pos.set(0);
+            set_guess_input.set(String::new());
+            set_message.set(None);
+        }
+        _ => set_message.set(Some((
+            "Please enter a 5-letter word!".to_string(),
+            MessageType::Info,
+        ))),
+    };
+
+    // Switch between AI recommendations and entering own guesses
+    let toggle_own_guesses = move |_| {
+        set_own_guesses.update(|own| *own = !*own);
+        let mut ai_val = ai.get_untracked();
+        let next_suggestions = suggest(&mut ai_val);
         set_ai.set(ai_val);
-        set_recommendation.set(next);
+        if !won.get_untracked() {
+            set_recommendation.set(next_suggestions.first().copied());
+        }
+        set_suggestions.set(next_suggestions);
         set_feedback.set([None; 5]);
-        set_history.set(Vec::new());
+        set_focus_pos.set(0);
+        set_guess_input.set(String::new());
         set_message.set(None);
-        set_won.set(false);
     };
 
-    // Reset
-    let reset = move |_| {
-        let current_lang = language.get();
-        let mut ai_val = HeuristicGuesser::new(current_lang.wordlist_array().to_vec());
-        let next = ai_val.make_guess();
+    // Choose which suggested word the feedback is entered for
+    let select_suggestion = move |word: [char; 5]| {
+        if recommendation.get() != Some(word) {
+            set_recommendation.set(Some(word));
+            set_feedback.set([None; 5]);
+            set_focus_pos.set(0);
+        }
+    };
+
+    // Start a new session in the given language, keeping the view settings (own guesses,
+    // spoiler-safe mode, candidate list); used when the language is changed
+    let start_over = move |new_lang: Language| {
+        let mut ai_val = HeuristicGuesser::new(new_lang.wordlist_array().to_vec());
+        let next_suggestions = suggest(&mut ai_val);
+        set_language.set(new_lang);
         set_ai.set(ai_val);
-        set_recommendation.set(next);
+        set_recommendation.set(next_suggestions.first().copied());
+        set_suggestions.set(next_suggestions);
         set_feedback.set([None; 5]);
         set_history.set(Vec::new());
         set_message.set(None);
         set_won.set(false);
+        set_focus_pos.set(0);
+        set_revealed.set(HashSet::new());
+        set_guess_input.set(String::new());
+        set_narrowing.set(None);
+    };
+
+    // Reset: start over in the current language
+    let reset = move |_| start_over(language.get());
+
+    // Reset to defaults: start over in English with AI recommendations, all rows shown and
+    // the candidate list collapsed
+    let reset_to_defaults = move |_| {
+        // Before starting over, so the fresh AI's suggestions are shown again
+        set_own_guesses.set(false);
+        set_spoiler_safe.set(false);
+        set_show_candidates.set(false);
+        start_over(Language::English);
+    };
+
+    // Handle key press on the recommendation: arrows move between tiles and cycle the
+    // feedback, C/M/A set it directly and Enter submits, like in the terminal assistant
+    let handle_key = move |ev: leptos::ev::KeyboardEvent| {
+        let pos = focus_pos.get();
+        match ev.key().as_str() {
+            "ArrowLeft" => set_focus_pos.set(pos.saturating_sub(1)),
+            "ArrowRight" => set_focus_pos.set((pos + 1).min(4)),
+            "ArrowUp" => {
+                let result = cycle_feedback(feedback.get()[pos], true);
+                set_feedback_at(pos, result);
+            }
+            "ArrowDown" => {
+                let result = cycle_feedback(feedback.get()[pos], false);
+                set_feedback_at(pos, result);
+            }
+            "c" | "C" => set_feedback_at(pos, LetterResult::Correct),
+            "m" | "M" => set_feedback_at(pos, LetterResult::Misplaced),
+            "a" | "A" => set_feedback_at(pos, LetterResult::Absent),
+            "Enter" => submit_feedback(),
+            _ => return,
+        }
+        // Keep the arrow keys from scrolling the page
+        ev.prevent_default();
     };
 
     view! {
@@ -126,7 +297,7 @@ pub fn AiSolver() -> impl IntoView {
             <Header
                 title="WORDLE SOLVER"
                 language=language.into()
-                on_language_change=change_language
+                on_language_change=start_over
                 show_nav=true
                 nav_to=Some("/")
                 nav_label=Some("Play Game")
@@ -137,14 +308,33 @@ pub fn AiSolver() -> impl IntoView {
             <div class="content">
                 {/* AI Recommendation */}
                 <div class="section">
-                    <div class="section__title">"AI Recommendation"</div>
+                    <div class="section__title">
+                        {move || {
+                            let title = if own_guesses.get() {
+                                "Your Guess".to_string()
+                            } else {
+                                format!("{}'s Recommendation", ai.with(|ai| ai.name()))
+                            };
+                            let guess_number = history.get().len() + 1;
+                            if won.get() || guess_number > DEFAULT_MAX_ATTEMPTS {
+                                title
+                            } else {
+                                format!("{} (guess {} of {})", title, guess_number, DEFAULT_MAX_ATTEMPTS)
+                            }
+                        }}
+                    </div>
 
                     {move || {
                         if let Some(word) = recommendation.get() {
                             let current_feedback = feedback.get();
+                            let focused = focus_pos.get();
 
                             view! {
-                                <div class="word-row">
+                                <div
+                                    class="word-row word-row--keyboard"
+                                    tabindex="0"
+                                    on:keydown=handle_key
+                                >
                                     {word
                                         .into_iter()
                                         .enumerate()
@@ -154,7 +344,11 @@ pub fn AiSolver() -> impl IntoView {
                                                 <InteractiveTile
                                                     letter=ch
                                                     result=fb
-                                                    on_click=move || toggle_feedback(i)
+                                                    focused=i == focused
+                                                    on_click=move || {
+                                                        set_focus_pos.set(i);
+                                                        toggle_feedback(i)
+                                                    }
                                                 />
                                             }
                                         })
@@ -162,6 +356,28 @@ pub fn AiSolver() -> impl IntoView {
                                 </div>
                             }
                             .into_any()
+                        } else if own_guesses.get() && !won.get() {
+                            view! {
+                                <div class="own-guess">
+                                    <input
+                                        type="text"
+                                        class="own-guess__input"
+                                        maxlength="5"
+                                        placeholder="Type your guess"
+                                        prop:value=move || guess_input.get()
+                                        on:input=move |ev| set_guess_input.set(event_target_value(&ev))
+                                        on:keydown=move |ev: leptos::ev::KeyboardEvent| {
+                                            if ev.key() == "Enter" {
+                                                use_own_guess();
+                                            }
+                                        }
+                                    />
+                                    <button class="button button--primary" on:click=move |_| use_own_guess()>
+                                        "Enter Feedback"
+                                    </button>
+                                </div>
+                            }
+                            .into_any()
                         } else {
                             view! {
                                 <div class="history__empty" style="padding: 2rem 0;">
@@ -171,8 +387,155 @@ pub fn AiSolver() -> impl IntoView {
                             .into_any()
                         }
                     }}
+
+                    {/* The other top suggestions, in case the recommended word does not fit */}
+                    {move || {
+                        let words = suggestions.get();
+                        if words.len() < 2 || won.get() {
+                            return ().into_any();
+                        }
+                        let active = recommendation.get();
+
+                        view! {
+                            <div class="opener-chips suggestion-chips">
+                                {words
+                                    .into_iter()
+                                    .map(|word| {
+                                        let label: String = word
+                                            .iter()
+                                            .map(|&c| display_upper(c))
+                                            .collect();
+                                        let class = if active == Some(word) {
+                                            "opener-chip opener-chip--active"
+                                        } else {
+                                            "opener-chip"
+                                        };
+                                        view! {
+                                            <button
+                                                class=class
+                                                on:click=move |_| select_suggestion(word)
+                                            >
+                                                {label}
+                                            </button>
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()}
+                            </div>
+                        }
+                        .into_any()
+                    }}
+
+                    {/* Why the AI recommends the word, from its own scoring */}
+                    {move || {
+                        let word = recommendation.get().filter(|_| !own_guesses.get() && !won.get())?;
+                        let reasons = ai.with(|ai| ai.explain_guess(&word));
+                        (!reasons.is_empty())
+                            .then(|| {
+                                view! {
+                                    <button
+                                        class="spoiler-toggle"
+                                        on:click=move |_| set_show_reasoning.update(|show| *show = !*show)
+                                    >
+                                        {move || if show_reasoning.get() { "Hide reasoning" } else { "Why this word?" }}
+                                    </button>
+                                    {move || {
+                                        show_reasoning
+                                            .get()
+                                            .then(|| {
+                                                view! {
+                                                    <ul class="reasoning-list">
+                                                        {reasons
+                                                            .iter()
+                                                            .map(|reason| view! { <li>{reason.clone()}</li> })
+                                                            .collect::<Vec<_>>()}
+                                                    </ul>
+                                                }
+                                            })
+                                    }}
+                                }
+                            })
+                    }}
                 </div>
 
+                {/* How far the last feedback narrowed down the candidates, and how much
+                     information was gathered overall */}
+                {move || {
+                    narrowing
+                        .get()
+                        .map(|(before, after)| {
+                            let remaining = if before == 0 {
+                                0.0
+                            } else {
+                                after as f64 / before as f64 * 100.0
+                            };
+                            let gathered = ai.with(|ai| ai.information_progress()) * 100.0;
+                            view! {
+                                <div class="section">
+                                    <div class="narrowing">
+                                        {format!(
+                                            "Candidates: {} → {}, eliminated {}",
+                                            before,
+                                            after,
+                                            before.saturating_sub(after)
+                                        )}
+                                    </div>
+                                    <div class="progress-bar">
+                                        <div
+                                            class="progress-bar__fill progress-bar__fill--shrink"
+                                            style=format!("width: {:.1}%", remaining)
+                                        ></div>
+                                    </div>
+                                    <div class="narrowing">
+                                        {format!("Information gathered: {:.0}%", gathered)}
+                                    </div>
+                                    <div class="progress-bar">
+                                        <div
+                                            class="progress-bar__fill progress-bar__fill--grow"
+                                            style=format!("width: {:.1}%", gathered)
+                                        ></div>
+                                    </div>
+                                </div>
+                            }
+                        })
+                }}
+
+                {/* Remaining words, when the user picks their own guesses */}
+                {move || {
+                    if !own_guesses.get() || won.get() {
+                        return ().into_any();
+                    }
+                    let listed = ai.with(|ai| {
+                        let candidates = ai.remaining_candidates().unwrap_or_default();
+                        let words: Vec<String> = candidates
+                            .iter()
+                            .take(MAX_LISTED_CANDIDATES)
+                            .map(|word| word.iter().map(|&c| display_upper(c)).collect())
+                            .collect();
+                        (candidates.len(), words.join(" "))
+                    });
+                    let (count, words) = listed;
+
+                    view! {
+                        <div class="section">
+                            <div class="section__title">
+                                {format!("{} words remaining", count)}
+                            </div>
+                            {move || {
+                                show_candidates
+                                    .get()
+                                    .then(|| view! { <div class="candidate-list">{words.clone()}</div> })
+                            }}
+                            <button
+                                class="spoiler-toggle"
+                                on:click=move |_| set_show_candidates.update(|show| *show = !*show)
+                            >
+                                {move || if show_candidates.get() { "Hide words" } else { "Show words" }}
+                            </button>
+                        </div>
+                    }
+                    .into_any()
+                }}
+
                 {/* Guess History */}
                 <div class="section">
                     <div class="section__title">"Guess History"</div>
@@ -182,10 +545,25 @@ pub fn AiSolver() -> impl IntoView {
                             if h.is_empty() {
                                 view! { <div class="history__empty">"No guesses yet"</div> }.into_any()
                             } else {
+                                let hide = spoiler_safe.get();
+                                let shown = revealed.get();
                                 h.into_iter()
-                                    .map(|(word, results)| {
+                                    .enumerate()
+                                    .map(|(i, (word, results))| {
+                                        let class = if hide && !shown.contains(&i) {
+                                            "word-row word-row--hidden"
+                                        } else {
+                                            "word-row"
+                                        };
                                         view! {
-                                            <div class="word-row">
+                                            <div
+                                                class=class
+                                                on:click=move |_| {
+                                                    if spoiler_safe.get_untracked() {
+                                                        set_revealed.update(|r| toggle_row(r, i));
+                                                    }
+                                                }
+                                            >
                                                 {word
                                                     .into_iter()
                                                     .zip(results)
@@ -208,7 +586,64 @@ pub fn AiSolver() -> impl IntoView {
                             }
                         }}
                     </div>
+
+                    <button
+                        class="spoiler-toggle"
+                        on:click=move |_| {
+                            set_spoiler_safe.update(|on| *on = !*on);
+                            set_revealed.set(HashSet::new());
+                        }
+                    >
+                        {move || if spoiler_safe.get() { "Show all rows" } else { "Hide rows" }}
+                    </button>
                 </div>
+
+                {/* Letters deduced from the history, to check the entered feedback at a glance */}
+                {move || {
+                    let h = history.get();
+                    if h.is_empty() || spoiler_safe.get() {
+                        return ().into_any();
+                    }
+                    let states = letter_states(&h);
+                    let group = |state: LetterResult, label: &'static str, class: &'static str| {
+                        let mut letters: Vec<char> = states
+                            .iter()
+                            .filter(|&(_, &s)| s == state)
+                            .map(|(&letter, _)| letter)
+                            .collect();
+                        letters.sort_unstable();
+                        view! {
+                            <div class="letter-group">
+                                <span class="letter-group__label">{label}</span>
+                                {if letters.is_empty() {
+                                    view! { <span class="letter-group__none">"none"</span> }.into_any()
+                                } else {
+                                    letters
+                                        .into_iter()
+                                        .map(|letter| {
+                                            view! {
+                                                <span class=format!("letter-chip {}", class)>
+                                                    {display_upper(letter)}
+                                                </span>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()
+                                        .into_any()
+                                }}
+                            </div>
+                        }
+                    };
+
+                    view! {
+                        <div class="section">
+                            <div class="section__title">"Letters"</div>
+                            {group(LetterResult::Correct, "Confirmed", "letter-chip--correct")}
+                            {group(LetterResult::Misplaced, "Present", "letter-chip--misplaced")}
+                            {group(LetterResult::Absent, "Eliminated", "letter-chip--absent")}
+                        </div>
+                    }
+                    .into_any()
+                }}
             </div>
 
             <div class="button-group">
@@ -216,12 +651,19 @@ pub fn AiSolver() -> impl IntoView {
                     if recommendation.get().is_some() && !won.get() {
                         view! {
                             <>
-                                <button class="button button--primary" on:click=submit_feedback>
+                                <button class="button button--primary" on:click=move |_| submit_feedback()>
                                     "Submit Feedback"
                                 </button>
-                                <button class="button button--yellow" on:click=mark_invalid>
-                                    "Not in Word List"
-                                </button>
+                                {move || {
+                                    (!own_guesses.get())
+                                        .then(|| {
+                                            view! {
+                                                <button class="button button--yellow" on:click=mark_invalid>
+                                                    "Not in Word List"
+                                                </button>
+                                            }
+                                        })
+                                }}
                             </>
                         }
                         .into_any()
@@ -230,12 +672,68 @@ pub fn AiSolver() -> impl IntoView {
                     }
                 }}
 
+                {move || {
+                    if history.get().is_empty() {
+                        ().into_any()
+                    } else {
+                        view! {
+                            <button class="button button--secondary" on:click=copy_state>
+                                "Copy State"
+                            </button>
+                        }
+                        .into_any()
+                    }
+                }}
+
+                <button class="button button--secondary" on:click=toggle_own_guesses>
+                    {move || {
+                        if own_guesses.get() { "Use AI Recommendations" } else { "Use My Own Guesses" }
+                    }}
+                </button>
+
                 <button class="button button--red" on:click=reset>
                     "Reset"
                 </button>
+
+                <button class="button button--secondary" on:click=reset_to_defaults>
+                    "Reset to Defaults"
+                </button>
             </div>
 
             <Footer />
         </div>
     }
 }
+
+/// The solver session as JSON: every recommendation with the feedback entered for it
+///
+/// `history` repeats the guesses in the format of the terminal assistant's `--history`
+/// option, so the session can be replayed with
+/// `wordle_ai_cli assistant --language <language> --history <history>`.
+fn solver_state_json(language: Language, history: &[([char; 5], [LetterResult; 5])]) -> String {
+    let language = match language {
+        Language::English => "en",
+        Language::German => "de",
+    };
+    let entries: Vec<(String, String)> = history
+        .iter()
+        .map(|(word, results)| (word.iter().collect(), format_feedback(results)))
+        .collect();
+
+    let guesses: Vec<Value> = entries
+        .iter()
+        .map(|(word, feedback)| json!({ "guess": word, "feedback": feedback }))
+        .collect();
+    let replay = entries
+        .iter()
+        .map(|(word, feedback)| format!("{}={}", word, feedback))
+        .collect::<Vec<_>>()
+        .join(";");
+
+    json!({
+        "language": language,
+        "guesses": guesses,
+        "history": replay,
+    })
+    .to_string()
+}
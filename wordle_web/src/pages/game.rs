@@ -1,106 +1,218 @@
 use leptos::prelude::*;
-use wordle_core::{Language, LetterResult};
+use leptos_router::hooks::use_query_map;
+use std::collections::HashSet;
+use wasm_bindgen::JsValue;
+use wordle_ai::{GuessReview, HeuristicGuesser, Knowledge, WordleAI, review_guesses};
+use wordle_core::{
+    DEFAULT_MAX_ATTEMPTS, GameError, GuessResult, Language, LetterResult, display_upper,
+    lowercase_letter, normalize_guess,
+};
 
+use super::{copy_to_clipboard, toggle_row};
+use crate::board_image::save_board_image;
 use crate::components::{Footer, Header, MessageBanner, MessageType, Tile};
 
-const MAX_ATTEMPTS: usize = 6;
-
 #[component]
 pub fn Game() -> impl IntoView {
+    // A shared link can preset the solution with `?word=<encoded word>`, or pick it from a
+    // seed with `?seed=<number>` to match `wordle_cli --seed`; `&lang=de` selects German
+    let (initial_game, initial_seed) = use_query_map().with_untracked(|query| {
+        let language = match query.get("lang").as_deref() {
+            Some("de") => Some(Language::German),
+            _ => None,
+        };
+        if let Some(game) = query
+            .get("word")
+            .and_then(|encoded| shared_game(&encoded, language))
+        {
+            return (game, None);
+        }
+        let language = language.unwrap_or_default();
+        match query.get("seed").and_then(|seed| seed.parse::<u64>().ok()) {
+            Some(seed) => (seeded_game(language, seed), Some(seed)),
+            None => (new_game(language), None),
+        }
+    });
+
     // State
-    let (language, set_language) = signal(Language::English);
-    let (solution, set_solution) = signal(pick_random_word(Language::English));
+    let (game, set_game) = signal(initial_game);
+    let (seed, set_seed) = signal(initial_seed);
+    let language = Signal::derive(move || game.get().language());
     let (current_guess, set_current_guess) = signal(String::new());
     let (guesses, set_guesses) = signal(Vec::<([char; 5], [LetterResult; 5])>::new());
     let (message, set_message) = signal(None::<(String, MessageType)>);
     let (game_over, set_game_over) = signal(false);
     let (_won, set_won) = signal(false);
+    let (hint_used, set_hint_used) = signal(false);
+    let (warned_guess, set_warned_guess) = signal(None::<[char; 5]>);
+    // Spoiler-safe mode hides past rows, e.g. while streaming, until each is clicked
+    let (spoiler_safe, set_spoiler_safe) = signal(false);
+    let (revealed, set_revealed) = signal(HashSet::<usize>::new());
+    // Every guess rated against the AI's choice, once asked for after the game
+    let (review, set_review) = signal(None::<Vec<GuessReview>>);
 
     // Submit guess
     let submit_guess = move || {
-        let guess = current_guess.get();
-        if guess.chars().count() != 5 {
+        let guess_chars = match normalize_guess(&current_guess.get(), game.get().language()) {
+            Ok(guess_chars) => guess_chars,
+            Err(err) => {
+                set_message.set(Some((describe_guess_error(&err), MessageType::Info)));
+                return;
+            }
+        };
+
+        // Warn once about a letter used more often than the feedback allows;
+        // submitting the same guess again plays it anyway
+        if let Some((letter, max)) = excess_letter(&game.get(), &guess_chars)
+            && warned_guess.get() != Some(guess_chars)
+        {
+            set_warned_guess.set(Some(guess_chars));
             set_message.set(Some((
-                "Word must be 5 letters long!".to_string(),
+                format!(
+                    "The word contains only {} {}! Press Enter again to guess anyway.",
+                    max,
+                    display_upper(letter)
+                ),
                 MessageType::Info,
             )));
             return;
         }
 
-        let guess_chars: [char; 5] = guess
-            .chars()
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap_or_else(|_| panic!("guess must be 5 chars"));
+        // Let the game check the guess and decide whether it is won, lost or goes on
+        let mut next_game = game.get();
+        let (results, outcome) = match next_game.take_guess(&guess_chars) {
+            Ok(GuessResult::Continue(results)) => (results, None),
+            Ok(GuessResult::Won(results)) => {
+                set_won.set(true);
+                let message = format!(
+                    "Congratulations! You won in {} guesses! {}",
+                    next_game.attempts(),
+                    next_game.language().describe_difficulty(&guess_chars)
+                );
+                (results, Some((message, MessageType::Success)))
+            }
+            Ok(GuessResult::Lost {
+                last_guess,
+                solution,
+            }) => {
+                let solution_str: String = solution.iter().collect();
+                let message = format!(
+                    "Game over! The word was: {}. {}",
+                    solution_str,
+                    next_game.language().describe_difficulty(&solution)
+                );
+                (last_guess, Some((message, MessageType::Error)))
+            }
+            Err(GameError::GameOver) => return,
+            Err(err) => {
+                set_message.set(Some((describe_guess_error(&err), MessageType::Error)));
+                return;
+            }
+        };
 
-        // Check if word is in wordlist
-        let wordlist = language.get().wordlist_array();
-        if !wordlist.contains(&guess_chars) {
-            set_message.set(Some((
-                "Word not in word list!".to_string(),
-                MessageType::Error,
-            )));
-            return;
+        set_game.set(next_game);
+        set_guesses.update(|g| g.push((guess_chars, results)));
+        set_current_guess.set(String::new());
+        set_game_over.set(outcome.is_some());
+        set_message.set(outcome);
+    };
+
+    // Reveal a suggested next guess from the AI, replaying the guesses made so far (once per game)
+    let reveal_hint = move |_| {
+        let mut ai = HeuristicGuesser::new(language.get().wordlist_array().to_vec());
+        for (guess, result) in guesses.get() {
+            ai.update(guess, result);
         }
 
-        // Calculate results
-        let results = wordle_core::take_guess(&solution.get(), &guess_chars);
+        set_hint_used.set(true);
+        set_message.set(Some(match ai.make_guess() {
+            Some(hint) => {
+                let hint_str: String = hint.iter().map(|&c| display_upper(c)).collect();
+                (format!("Hint: try {}", hint_str), MessageType::Info)
+            }
+            None => ("No hint available".to_string(), MessageType::Error),
+        }));
+    };
 
-        // Check if won
-        if results.iter().all(|&r| r == LetterResult::Correct) {
-            set_guesses.update(|g| g.push((guess_chars, results)));
-            set_game_over.set(true);
-            set_won.set(true);
-            set_message.set(Some((
-                format!(
-                    "Congratulations! You won in {} guesses!",
-                    guesses.get().len() + 1
-                ),
-                MessageType::Success,
-            )));
-            set_current_guess.set(String::new());
-            return;
-        }
+    // Rate every guess against the AI's choice at that point
+    let review_game = move |_| {
+        set_review.set(Some(review_guesses(language.get(), &guesses.get())));
+    };
+
+    // Copy a link that starts a game with the same solution, without showing the word
+    let challenge_friend = move |_| {
+        let copied = challenge_link(&game.get()).and_then(|link| copy_to_clipboard(&link));
+        set_message.set(Some(match copied {
+            Ok(()) => (
+                "Challenge link copied to clipboard".to_string(),
+                MessageType::Info,
+            ),
+            Err(_) => (
+                "Could not copy the challenge link!".to_string(),
+                MessageType::Error,
+            ),
+        }));
+    };
 
-        // Check if lost
-        if guesses.get().len() + 1 >= MAX_ATTEMPTS {
-            set_guesses.update(|g| g.push((guess_chars, results)));
-            set_game_over.set(true);
-            let solution_str: String = solution.get().iter().collect();
+    // Download the finished board as an image
+    let save_image = move |_| {
+        if save_board_image(&guesses.get()).is_err() {
             set_message.set(Some((
-                format!("Game over! The word was: {}", solution_str),
+                "Could not save the image!".to_string(),
                 MessageType::Error,
             )));
-            set_current_guess.set(String::new());
-            return;
         }
-
-        // Continue game
-        set_guesses.update(|g| g.push((guess_chars, results)));
-        set_current_guess.set(String::new());
-        set_message.set(None);
     };
 
-    // Change language
-    let change_language = move |new_lang: Language| {
-        set_language.set(new_lang);
-        set_solution.set(pick_random_word(new_lang));
+    // Switch to another game, keeping spoiler-safe mode
+    let start_game = move |next_game: wordle_core::Game, next_seed: Option<u64>| {
+        set_game.set(next_game);
+        set_seed.set(next_seed);
         set_current_guess.set(String::new());
         set_guesses.set(Vec::new());
         set_message.set(None);
         set_game_over.set(false);
         set_won.set(false);
+        set_hint_used.set(false);
+        set_warned_guess.set(None);
+        set_revealed.set(HashSet::new());
+        set_review.set(None);
     };
 
-    // Reset
-    let reset = move |_| {
-        let current_lang = language.get();
-        set_solution.set(pick_random_word(current_lang));
-        set_current_guess.set(String::new());
-        set_guesses.set(Vec::new());
-        set_message.set(None);
-        set_game_over.set(false);
-        set_won.set(false);
+    // Start a new random game in the given language; used when the language is changed
+    let start_over = move |new_lang: Language| start_game(new_game(new_lang), None);
+
+    // New game: start over in the current language
+    let reset = move |_| start_over(language.get());
+
+    // New random word: a game from a fresh seed, which is shown so the game can be shared
+    let reroll = move |_| {
+        let new_seed = random_seed();
+        start_game(seeded_game(language.get(), new_seed), Some(new_seed));
+    };
+
+    // Copy a link that starts the seeded game, like `wordle_cli --seed`
+    let copy_seed_link = move |_| {
+        let Some(seed) = seed.get() else {
+            return;
+        };
+        let copied = seed_link(seed, language.get()).and_then(|link| copy_to_clipboard(&link));
+        set_message.set(Some(match copied {
+            Ok(()) => (
+                "Seed link copied to clipboard".to_string(),
+                MessageType::Info,
+            ),
+            Err(_) => (
+                "Could not copy the seed link!".to_string(),
+                MessageType::Error,
+            ),
+        }));
+    };
+
+    // Reset to defaults: start over in English with all rows shown
+    let reset_to_defaults = move |_| {
+        set_spoiler_safe.set(false);
+        start_over(Language::English);
     };
 
     // Handle key press
@@ -120,10 +232,10 @@ pub fn Game() -> impl IntoView {
             // Use chars().count() to properly handle multi-byte UTF-8 characters
             if key.chars().count() == 1
                 && let Some(c) = key.chars().next()
-                && c.is_alphabetic()
+                && language.get().is_valid_char(c)
             {
-                // Use to_lowercase() to preserve ß
-                let lower = c.to_lowercase().next().unwrap_or(c);
+                // Keeps ß, and turns the capital ẞ into ß
+                let lower = lowercase_letter(c);
                 set_current_guess.update(|g| {
                     g.push(lower);
                 });
@@ -141,11 +253,12 @@ pub fn Game() -> impl IntoView {
         >
             <Header
                 title="WORDLE"
-                language=language.into()
-                on_language_change=change_language
+                language=language
+                on_language_change=start_over
                 show_nav=true
                 nav_to=Some("/ai")
                 nav_label=Some("AI Solver")
+                badge=Signal::derive(move || seed.get().map(|seed| format!("Seed {}", seed)))
             />
 
             <MessageBanner message=message.into() />
@@ -154,15 +267,48 @@ pub fn Game() -> impl IntoView {
                 <div class="section">
                     <div class="section__title">"Guess the 5-letter word"</div>
 
+                    {/* The seed of a seeded game, so it can be shared again */}
+                    {move || {
+                        seed.get()
+                            .map(|seed| {
+                                view! {
+                                    <div class="seed-info">
+                                        {format!("Share with {}", seed_query(seed, language.get()))}
+                                        <button
+                                            class="button button--small button--secondary"
+                                            on:click=copy_seed_link
+                                        >
+                                            "Copy Seed Link"
+                                        </button>
+                                    </div>
+                                }
+                            })
+                    }}
+
                     <div class="game-board">
                         {/* Previous guesses */}
                         {move || {
+                            let hide = spoiler_safe.get();
+                            let shown = revealed.get();
                             guesses
                                 .get()
                                 .into_iter()
-                                .map(|(word, results)| {
+                                .enumerate()
+                                .map(|(i, (word, results))| {
+                                    let class = if hide && !shown.contains(&i) {
+                                        "word-row word-row--hidden"
+                                    } else {
+                                        "word-row"
+                                    };
                                     view! {
-                                        <div class="word-row">
+                                        <div
+                                            class=class
+                                            on:click=move |_| {
+                                                if spoiler_safe.get_untracked() {
+                                                    set_revealed.update(|r| toggle_row(r, i));
+                                                }
+                                            }
+                                        >
                                             {word
                                                 .into_iter()
                                                 .zip(results)
@@ -205,10 +351,11 @@ pub fn Game() -> impl IntoView {
 
                         {/* Empty rows */}
                         {move || {
+                            let max_attempts = game.get().max_attempts();
                             let remaining = if game_over.get() {
-                                MAX_ATTEMPTS.saturating_sub(guesses.get().len())
+                                max_attempts.saturating_sub(guesses.get().len())
                             } else {
-                                MAX_ATTEMPTS.saturating_sub(guesses.get().len() + 1)
+                                max_attempts.saturating_sub(guesses.get().len() + 1)
                             };
 
                             (0..remaining)
@@ -224,8 +371,78 @@ pub fn Game() -> impl IntoView {
                                 .collect::<Vec<_>>()
                         }}
                     </div>
+
+                    <button
+                        class="spoiler-toggle"
+                        on:click=move |_| {
+                            set_spoiler_safe.update(|on| *on = !*on);
+                            set_revealed.set(HashSet::new());
+                        }
+                    >
+                        {move || if spoiler_safe.get() { "Show all rows" } else { "Hide rows" }}
+                    </button>
                 </div>
 
+                {/* Suggested openers, until the first guess is made */}
+                {move || {
+                    if guesses.get().is_empty() && !game_over.get() {
+                        view! {
+                            <div class="section">
+                                <div class="section__title">"Need a starting word?"</div>
+                                <div class="opener-chips">
+                                    {language
+                                        .get()
+                                        .recommended_openers()
+                                        .iter()
+                                        .map(|opener| {
+                                            let word: String = opener.iter().collect();
+                                            let label: String = opener
+                                                .iter()
+                                                .map(|&c| display_upper(c))
+                                                .collect();
+                                            view! {
+                                                <button
+                                                    class="opener-chip"
+                                                    on:click=move |_| {
+                                                        set_current_guess.set(word.clone())
+                                                    }
+                                                >
+                                                    {label}
+                                                </button>
+                                            }
+                                        })
+                                        .collect::<Vec<_>>()}
+                                </div>
+                            </div>
+                        }
+                        .into_any()
+                    } else {
+                        ().into_any()
+                    }
+                }}
+
+                {/* Coaching review, once asked for after the game */}
+                {move || {
+                    review
+                        .get()
+                        .map(|reviews| {
+                            view! {
+                                <div class="section">
+                                    <div class="section__title">"Coaching"</div>
+                                    <ul class="review-list">
+                                        {reviews
+                                            .iter()
+                                            .map(|entry| {
+                                                let (text, class) = describe_review(entry);
+                                                view! { <li class=class>{text}</li> }
+                                            })
+                                            .collect::<Vec<_>>()}
+                                    </ul>
+                                </div>
+                            }
+                        })
+                }}
+
                 <div class="section">
                     <div class="instructions">
                         <p>"Type your guess and press Enter"</p>
@@ -246,9 +463,52 @@ pub fn Game() -> impl IntoView {
             </div>
 
             <div class="button-group">
+                {move || {
+                    if game_over.get() {
+                        view! {
+                            <button class="button button--primary" on:click=save_image>
+                                "Save Image"
+                            </button>
+                            {move || {
+                                review
+                                    .with(Option::is_none)
+                                    .then(|| {
+                                        view! {
+                                            <button class="button button--yellow" on:click=review_game>
+                                                "Review Guesses"
+                                            </button>
+                                        }
+                                    })
+                            }}
+                        }
+                        .into_any()
+                    } else if !hint_used.get() {
+                        view! {
+                            <button class="button button--yellow" on:click=reveal_hint>
+                                "Hint"
+                            </button>
+                        }
+                        .into_any()
+                    } else {
+                        ().into_any()
+                    }
+                }}
+
+                <button class="button button--secondary" on:click=challenge_friend>
+                    "Challenge a Friend"
+                </button>
+
                 <button class="button button--red" on:click=reset>
                     "New Game"
                 </button>
+
+                <button class="button button--secondary" on:click=reroll>
+                    "New Random Word"
+                </button>
+
+                <button class="button button--secondary" on:click=reset_to_defaults>
+                    "Reset to Defaults"
+                </button>
             </div>
 
             <Footer />
@@ -256,10 +516,114 @@ pub fn Game() -> impl IntoView {
     }
 }
 
-fn pick_random_word(language: Language) -> [char; 5] {
-    let wordlist = language.wordlist_array();
-    let mut bytes = [0u8; 4];
-    getrandom::fill(&mut bytes).expect("Failed to get random bytes");
-    let index = u32::from_le_bytes(bytes) as usize % wordlist.len();
-    wordlist[index]
+/// Describe how a guess compares to the AI's choice, with the CSS class of its list entry
+fn describe_review(entry: &GuessReview) -> (String, &'static str) {
+    let class = if entry.is_optimal() {
+        "review-list__item review-list__item--optimal"
+    } else {
+        "review-list__item"
+    };
+    (entry.summary(), class)
+}
+
+/// Start a new game with a random solution
+///
+/// Without a source of randomness, the solution is picked from the current time instead of
+/// panicking, and a warning is logged.
+fn new_game(language: Language) -> wordle_core::Game {
+    let solution = language.try_random_word().unwrap_or_else(|| {
+        leptos::logging::warn!("No randomness available, picking the word from the current time");
+        language.random_word_seeded(js_sys::Date::now() as u64)
+    });
+    wordle_core::Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, solution)
+        .expect("random words are in the word list")
+}
+
+/// Find a letter that the guess contains more often than the feedback so far allows
+fn excess_letter(game: &wordle_core::Game, guess: &[char; 5]) -> Option<(char, u8)> {
+    let mut knowledge = Knowledge::new();
+    for &(word, result) in game.guesses() {
+        knowledge.update(word, result);
+    }
+
+    let mut max_contain: Vec<(char, u8)> = knowledge
+        .max_contain()
+        .iter()
+        .map(|(&letter, &max)| (letter, max))
+        .collect();
+    max_contain.sort();
+    max_contain
+        .into_iter()
+        .find(|&(letter, max)| guess.iter().filter(|&&c| c == letter).count() > max as usize)
+}
+
+/// Tell the player why a guess was not taken
+fn describe_guess_error(err: &GameError) -> String {
+    match err {
+        GameError::WordNotInList => "Word not in word list!".to_string(),
+        GameError::GameOver => "The game is already over!".to_string(),
+        GameError::WrongLength => "Word must be 5 letters long!".to_string(),
+        GameError::InvalidChar(c) => format!("'{}' is not a letter!", display_upper(*c)),
+        GameError::ViolatesHardMode => "Hard mode: use every hint found so far!".to_string(),
+    }
+}
+
+/// Start a game whose solution is picked from the seed, like `wordle_cli --seed`
+fn seeded_game(language: Language, seed: u64) -> wordle_core::Game {
+    wordle_core::Game::new_with_seed(DEFAULT_MAX_ATTEMPTS, language, seed)
+        .expect("word list must not be empty")
+}
+
+/// A random seed for [`seeded_game`], short enough to read out when sharing it
+fn random_seed() -> u64 {
+    (js_sys::Math::random() * 1_000_000.0) as u64
+}
+
+/// Query parameter selecting the language, empty for the default English
+fn lang_param(language: Language) -> &'static str {
+    match language {
+        Language::English => "",
+        Language::German => "&lang=de",
+    }
+}
+
+/// Query that starts the seeded game, e.g. `?seed=42&lang=de`
+fn seed_query(seed: u64, language: Language) -> String {
+    format!("?seed={}{}", seed, lang_param(language))
+}
+
+/// Link to this page that starts the seeded game, see [`seed_query`]
+fn seed_link(seed: u64, language: Language) -> Result<String, JsValue> {
+    let location = web_sys::window().ok_or("no window")?.location();
+    Ok(format!(
+        "{}{}{}",
+        location.origin()?,
+        location.pathname()?,
+        seed_query(seed, language)
+    ))
+}
+
+/// Link to this page that starts a game with the same solution and language, see
+/// [`shared_game`]
+fn challenge_link(game: &wordle_core::Game) -> Result<String, JsValue> {
+    let location = web_sys::window().ok_or("no window")?.location();
+    Ok(format!(
+        "{}{}?word={}{}",
+        location.origin()?,
+        location.pathname()?,
+        game.share_code(),
+        lang_param(game.language())
+    ))
+}
+
+/// Start a game with a shared solution, in `language` if its word list contains the word,
+/// otherwise in the first language whose word list does, e.g. for links without `lang`
+fn shared_game(encoded: &str, language: Option<Language>) -> Option<wordle_core::Game> {
+    let word = wordle_core::decode_word(encoded)?;
+    language
+        .into_iter()
+        .chain(Language::all().iter().copied())
+        .find_map(|language| {
+            wordle_core::Game::new_with_word(DEFAULT_MAX_ATTEMPTS, language, word).ok()
+        })
 }
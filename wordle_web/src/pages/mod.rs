@@ -5,3 +5,20 @@ pub mod not_found;
 pub use ai_solver::AiSolver;
 pub use game::Game;
 pub use not_found::NotFound;
+
+use std::collections::HashSet;
+use wasm_bindgen::JsValue;
+
+/// Reveal a row hidden by spoiler-safe mode, or hide it again
+fn toggle_row(revealed: &mut HashSet<usize>, row: usize) {
+    if !revealed.remove(&row) {
+        revealed.insert(row);
+    }
+}
+
+/// Write text to the clipboard, without waiting for the browser to confirm
+fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let _ = window.navigator().clipboard().write_text(text);
+    Ok(())
+}
@@ -64,6 +64,14 @@ body {
     justify-content: center;
 }
 
+.header__badge {
+    font-size: 13px;
+    font-weight: 600;
+    letter-spacing: 0;
+    color: #878a8c;
+    white-space: nowrap;
+}
+
 /* Language Select */
 .language-select {
     font-family: 'Open Sans', sans-serif;
@@ -193,6 +201,16 @@ body {
     transform: scale(0.95);
 }
 
+/* Keyboard focus on the solver's feedback tiles */
+.word-row--keyboard:focus {
+    outline: none;
+}
+
+.word-row--keyboard:focus .tile--focused {
+    outline: 3px solid #1a1a1b;
+    outline-offset: 2px;
+}
+
 /* Small tiles for history */
 .tile--small {
     width: 40px;
@@ -210,6 +228,23 @@ body {
     max-width: 330px;
 }
 
+/* Spoiler-safe rows, revealed by clicking them */
+.word-row--hidden {
+    filter: blur(8px);
+    cursor: pointer;
+}
+
+.spoiler-toggle {
+    display: block;
+    margin: 8px auto 0;
+    background: none;
+    border: none;
+    color: #878a8c;
+    font-size: 12px;
+    text-decoration: underline;
+    cursor: pointer;
+}
+
 .word-row--current {
     position: relative;
 }
@@ -346,6 +381,143 @@ body {
     background: #878a8c;
 }
 
+/* Letters deduced in the solver */
+.letter-group {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    justify-content: center;
+    gap: 4px;
+    margin-bottom: 8px;
+}
+
+.letter-group__label {
+    font-size: 13px;
+    font-weight: 600;
+    color: #1a1a1b;
+    width: 80px;
+    text-align: right;
+    margin-right: 4px;
+}
+
+.letter-group__none {
+    font-size: 13px;
+    color: #878a8c;
+}
+
+.letter-chip {
+    font-family: 'Open Sans', sans-serif;
+    font-weight: 700;
+    font-size: 13px;
+    color: #ffffff;
+    min-width: 24px;
+    padding: 2px 4px;
+    border-radius: 4px;
+    text-align: center;
+}
+
+.letter-chip--correct {
+    background-color: #6aaa64;
+}
+
+.letter-chip--misplaced {
+    background-color: #c9b458;
+}
+
+.letter-chip--absent {
+    background-color: #787c7e;
+}
+
+/* Own guesses in the solver */
+.own-guess {
+    display: flex;
+    justify-content: center;
+    gap: 8px;
+}
+
+.own-guess__input {
+    font-family: 'Open Sans', sans-serif;
+    font-size: 16px;
+    letter-spacing: 2px;
+    text-transform: uppercase;
+    width: 140px;
+    padding: 8px 12px;
+    border: 2px solid #d3d6da;
+    border-radius: 4px;
+}
+
+.candidate-list {
+    color: #1a1a1b;
+    font-size: 13px;
+    letter-spacing: 1px;
+    line-height: 1.8;
+    text-align: center;
+    word-spacing: 6px;
+}
+
+.reasoning-list {
+    list-style: none;
+    color: #1a1a1b;
+    font-size: 13px;
+    line-height: 1.8;
+    text-align: center;
+}
+
+/* Candidate narrowing: the first bar shrinks from all previous candidates to the remaining
+   ones, the second grows to the information gathered so far */
+.narrowing {
+    color: #1a1a1b;
+    font-size: 14px;
+    text-align: center;
+    margin: 8px 0;
+}
+
+.progress-bar {
+    height: 10px;
+    background-color: #d3d6da;
+    border-radius: 5px;
+    overflow: hidden;
+}
+
+.progress-bar__fill {
+    height: 100%;
+    background-color: #6aaa64;
+    animation-duration: 0.8s;
+    animation-timing-function: ease-out;
+}
+
+.progress-bar__fill--shrink {
+    animation-name: progress-shrink;
+}
+
+.progress-bar__fill--grow {
+    background-color: #c9b458;
+    animation-name: progress-grow;
+}
+
+@keyframes progress-shrink {
+    from {
+        width: 100%;
+    }
+}
+
+@keyframes progress-grow {
+    from {
+        width: 0;
+    }
+}
+
+.seed-info {
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    gap: 10px;
+    flex-wrap: wrap;
+    color: #878a8c;
+    font-size: 13px;
+    margin-bottom: 12px;
+}
+
 .history__empty {
     text-align: center;
     color: #878a8c;
@@ -364,6 +536,64 @@ body {
 }
 
 
+/* Opener suggestions */
+.opener-chips {
+    display: flex;
+    justify-content: center;
+    flex-wrap: wrap;
+    gap: 8px;
+}
+
+.opener-chip {
+    font-family: 'Open Sans', sans-serif;
+    font-weight: 600;
+    font-size: 13px;
+    letter-spacing: 1px;
+    background-color: #ffffff;
+    color: #1a1a1b;
+    border: 2px solid #d3d6da;
+    border-radius: 16px;
+    padding: 4px 12px;
+    cursor: pointer;
+    transition: border-color 0.2s ease;
+}
+
+.opener-chip:hover {
+    border-color: #878a8c;
+}
+
+.opener-chip--active {
+    background-color: #6aaa64;
+    border-color: #6aaa64;
+    color: #ffffff;
+}
+
+.opener-chip--active:hover {
+    border-color: #6aaa64;
+}
+
+.suggestion-chips {
+    margin-top: 12px;
+}
+
+.review-list {
+    list-style: none;
+    padding: 0;
+    margin: 0;
+    font-size: 14px;
+}
+
+.review-list__item {
+    padding: 6px 10px;
+    margin-bottom: 6px;
+    border-left: 4px solid #c9b458;
+    background-color: #f6f7f8;
+}
+
+.review-list__item--optimal {
+    border-left-color: #6aaa64;
+}
+
 /* Instructions */
 .instructions {
     text-align: center;